@@ -0,0 +1,13 @@
+//! The options shared by the render bench and the unit test checking the bytes per key press.
+use pick_a_boo::{Item, Options, OptionsBuilder};
+
+/// Build the options of `count` items with descriptions.
+/// The keys are CJK ideographs, so that they stay distinct beyond the digits and the letters.
+pub fn options(count: u32) -> Options {
+    let mut builder = OptionsBuilder::default();
+    for i in 0..count {
+        let key = char::from_u32(0x4e00 + i).expect("CJK ideographs");
+        builder.item(Item::new_full(format!("Item {i:03}"), format!("{i}"), key, Some(format!("Description of the item {i}"))));
+    }
+    builder.build().expect("the keys are distinct")
+}
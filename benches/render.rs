@@ -0,0 +1,49 @@
+//! Benchmark of the bytes written to the terminal per key press.
+//! Run with `cargo bench`, and then the numbers of each picker are printed.
+mod common;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use common::options;
+use pick_a_boo::{DescriptionShowMode, Layout, Options, Picker, PickerBuilder, PickerState};
+use std::time::Instant;
+
+/// The number of the key presses measured for each picker.
+const KEY_PRESSES: usize = 100;
+const WIDTH: usize = 80;
+const HEIGHT: usize = 200;
+
+/// Press the key repeatedly, and print the bytes of the first frame and the average bytes per key press.
+fn measure(name: &str, picker: &Picker, options: Options, key: KeyCode) {
+    let mut state = PickerState::new(picker, "Choose an item", options);
+    let mut out = Vec::new();
+    state.draw(&mut out, WIDTH, HEIGHT).expect("writing to Vec never fails");
+    let first = out.len();
+    let mut total = 0;
+    let start = Instant::now();
+    for _ in 0..KEY_PRESSES {
+        state.handle_key(KeyEvent::new(key, KeyModifiers::NONE));
+        out.clear();
+        state.draw(&mut out, WIDTH, HEIGHT).expect("writing to Vec never fails");
+        total += out.len();
+    }
+    let elapsed = start.elapsed() / KEY_PRESSES as u32;
+    println!("{name:<28} first frame: {first:>6} bytes, per key press: {:>5} bytes, {elapsed:>10.2?}", total / KEY_PRESSES);
+}
+
+fn main() {
+    let inline = PickerBuilder::default().build().expect("valid picker");
+    measure("inline, 8 items", &inline, options(8), KeyCode::Right);
+    let described = PickerBuilder::default()
+        .description_show_mode(DescriptionShowMode::All)
+        .build().expect("valid picker");
+    measure("inline, 120 descriptions", &described, options(120), KeyCode::Right);
+    let list = PickerBuilder::default()
+        .layout(Layout::List)
+        .description_show_mode(DescriptionShowMode::All)
+        .build().expect("valid picker");
+    measure("list, 120 descriptions", &list, options(120), KeyCode::Down);
+    let grid = PickerBuilder::default()
+        .layout(Layout::Grid)
+        .build().expect("valid picker");
+    measure("grid, 120 items", &grid, options(120), KeyCode::Down);
+}
@@ -8,6 +8,7 @@ fn main() -> std::io::Result<()> {
     let mut picker = pick_a_boo::PickerBuilder::default()
         .alternate_screen(true)
         .allow_wrap(true)
+        .unselected_display(pick_a_boo::UnselectedDisplay::ShortLabel)
         .description_show_mode(pick_a_boo::DescriptionShowMode::All)
         .description_name_width(pick_a_boo::DescriptionNameWidth::Auto)
         .build().expect("Failed to build Picker");
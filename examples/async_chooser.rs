@@ -0,0 +1,20 @@
+//! Choose an option while a background job is running, and give up when the job finishes first.
+//! Run with `cargo run --example async_chooser --features async`.
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() -> pick_a_boo::Result<()> {
+    let mut picker = pick_a_boo::PickerBuilder::default()
+        .summary(pick_a_boo::Summary::default())
+        .build()?;
+    let job = tokio::time::sleep(Duration::from_secs(10));
+    tokio::select! {
+        answer = picker.yes_or_no_async("Stop the job?", false) => match answer? {
+            Some(true) => println!("Stopping the job."),
+            Some(false) => println!("Continuing the job."),
+            None => println!("Cancelled."),
+        },
+        _ = job => println!("The job has finished."),
+    }
+    Ok(())
+}
@@ -5,7 +5,11 @@ fn main() -> std::io::Result<()> {
         .item(pick_a_boo::item!("Maybe", key = 'm', description = "I haven't tried it yet"))
         .item(pick_a_boo::item!("No", "n", "I don't like it"))
         .build().expect("Failed to build Options");
-    let mut picker = pick_a_boo::Picker::default();
+    let mut picker = pick_a_boo::PickerBuilder::default()
+        .description_show_mode(pick_a_boo::DescriptionShowMode::CurrentOnly)
+        .summary(pick_a_boo::Summary::default())
+        .footer(pick_a_boo::Footer::default())
+        .build().expect("Failed to build Picker");
 
     let answer = picker.choose("Do you like Rust?", options)?;
     match answer {
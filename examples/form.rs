@@ -0,0 +1,22 @@
+use pick_a_boo::Form;
+
+#[derive(Debug, Default, Form)]
+struct Config {
+    #[prompt("Project name")]
+    name: String,
+    #[prompt("License", options = ["MIT", "Apache-2.0", "GPL-3.0"])]
+    license: String,
+    #[prompt("Number of workers")]
+    workers: u32,
+    #[prompt("Publish to crates.io?")]
+    publish: bool,
+}
+
+fn main() -> std::io::Result<()> {
+    let mut picker = pick_a_boo::Picker::default();
+    match Config::prompt(&mut picker)? {
+        Some(config) => println!("{config:?}"),
+        None => println!("Cancelled."),
+    }
+    Ok(())
+}
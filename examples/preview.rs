@@ -0,0 +1,18 @@
+fn main() -> std::io::Result<()> {
+    let options = pick_a_boo::Options::from(&["Cargo.toml", "README.md", "LICENSE"])
+        .expect("Failed to create Options");
+    let mut picker = pick_a_boo::PickerBuilder::default()
+        .layout(pick_a_boo::Layout::List)
+        .preview(pick_a_boo::Preview::new(|item| {
+            std::fs::read_to_string(&item.long_label)
+                .unwrap_or_else(|e| format!("{}: {e}", item.long_label))
+        }).position(pick_a_boo::PreviewPosition::Beside))
+        .build().expect("Failed to build Picker");
+
+    let answer = picker.choose("Which file?", options)?;
+    match answer {
+        Some(choice) => println!("You chose: {choice}"),
+        None => println!("Cancelled."),
+    }
+    Ok(())
+}
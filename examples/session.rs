@@ -0,0 +1,15 @@
+//! Ask a sequence of questions in one terminal session, without flickering between them.
+fn main() -> pick_a_boo::Result<()> {
+    let alternate = std::env::args().any(|arg| arg == "--alternate");
+    let mut picker = pick_a_boo::PickerBuilder::default()
+        .alternate_screen(alternate)
+        .summary(pick_a_boo::Summary::default())
+        .build()?;
+    let mut session = picker.session();
+    let name = session.input("Project name?", "my-app")?;
+    let license = session.choose("License?", pick_a_boo::Options::from(&["MIT", "Apache-2.0", "GPL-3.0"])?)?;
+    let publish = session.yes_or_no("Publish to crates.io?", false)?;
+    session.close()?;
+    println!("name: {name:?}, license: {license:?}, publish: {publish:?}");
+    Ok(())
+}
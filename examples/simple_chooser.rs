@@ -1,6 +1,6 @@
 fn main() -> std::io::Result<()> {
     let options = pick_a_boo::Options::from(
-        &vec!["Yes", "Maybe", "So so", "No"])
+        &["Yes", "Maybe", "So so", "No"])
         .expect("Failed to create Options");
     let answer = pick_a_boo::choose(
         "Do you like Rust?", options);
@@ -11,7 +11,7 @@ fn main() -> std::io::Result<()> {
         Ok(Some(choice)) if &choice == "No"    => println!("I don't like it"),
         Ok(Some(_))   => panic!("never reach here!"),
         Ok(None)      => println!("You cancelled"),
-        Err(e) => return Err(e),
+        Err(e) => return Err(e.into()),
     }
     Ok(())
 }
@@ -1,3 +1,7 @@
+//! Procedural macros for the `pick-a-boo` crate.
+//!
+//! ## `item!`
+//!
 //! Macro to create an [pick_a_boo::Item] instance with flexible arguments.
 //! 
 //! Usage examples:
@@ -14,9 +18,28 @@
 //! let h = item!("Theta", description = "first", key = 't', short = "T");  // order doesn't matter
 //! let i = item!("", description = "empty");        // empty name then key and short are '\0'
 //! ```
+//!
+//! ## `#[derive(Form)]`
+//!
+//! Derive macro to implement [pick_a_boo::Form] for a struct with named fields.
+//! Each field annotated with `#[prompt(...)]` is asked in declaration order.
+//! The field names are the prompt ids for the preseeded answers (see `pick_a_boo::Picker::id`).
+//!
+//! ```rust
+//! #[derive(Default, pick_a_boo::Form)]
+//! struct Config {
+//!     #[prompt("Project name")]
+//!     name: String,
+//!     #[prompt("License", options = ["MIT", "Apache-2.0", "GPL-3.0"])]
+//!     license: String,
+//!     #[prompt("Publish to crates.io?")]
+//!     publish: bool,
+//!     version: String, // not asked, keeps the value of `Default`
+//! }
+//! ```
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse::{Parse, ParseStream}, parse_macro_input, Expr, Ident, Token, Result};
+use syn::{parse::{Parse, ParseStream}, parse_macro_input, Data, DeriveInput, Expr, ExprArray, Fields, Ident, LitStr, Token, Type, Result};
 use syn::spanned::Spanned;
 
 struct ItemInput {
@@ -137,3 +160,131 @@ pub fn item(input: TokenStream) -> TokenStream {
         }
     }}.into()
 }
+
+/// The arguments of the `#[prompt(...)]` attribute.
+/// The first argument is the prompt string, and `options = [...]` is optional.
+struct PromptArgs {
+    prompt: LitStr,
+    options: Option<ExprArray>,
+}
+
+impl Parse for PromptArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let prompt = input.parse()?;
+        let mut options = None;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() { break; }
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match name.to_string().as_str() {
+                "options" => options = Some(input.parse()?),
+                _ => return Err(syn::Error::new(name.span(), format!("Unknown argument name: {name}"))),
+            }
+        }
+        Ok(PromptArgs { prompt, options })
+    }
+}
+
+fn is_bool(ty: &Type) -> bool {
+    match ty {
+        Type::Path(p) => p.qself.is_none() && p.path.is_ident("bool"),
+        _ => false,
+    }
+}
+
+#[proc_macro_derive(Form, attributes(prompt))]
+pub fn derive_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_form(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand_form(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+    let path = quote! { ::pick_a_boo };
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new(input.ident.span(), "Form can only be derived for structs with named fields")),
+        },
+        _ => return Err(syn::Error::new(input.ident.span(), "Form can only be derived for structs")),
+    };
+
+    let mut steps = Vec::new();
+    for field in fields {
+        let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("prompt")) else {
+            continue;
+        };
+        let args: PromptArgs = attr.parse_args()?;
+        let name = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+        let prompt = &args.prompt;
+        let id = name.to_string();
+        let parse = quote! {
+            |answer: &str| <#ty as ::std::str::FromStr>::from_str(answer)
+                .map_err(|e| #path::Error::InvalidAnswer {
+                    prompt: #prompt.to_string(),
+                    answer: answer.to_string(),
+                    reason: e.to_string(),
+                })
+        };
+        let step = if let Some(options) = &args.options {
+            let labels = &options.elems;
+            quote! {
+                let parse = #parse;
+                let items = #path::Item::from_labels(&[#labels]);
+                let current = self.#name.to_string();
+                let current = items.iter().position(|item| item.long_label == current).unwrap_or(0);
+                let options = #path::OptionsBuilder::default()
+                    .items(items)
+                    .current(current)
+                    .build()?;
+                self.#name = match picker.id(#id).choose(#prompt, options)? {
+                    Some(answer) => parse(&answer)?,
+                    None => return Ok(None),
+                };
+            }
+        } else if is_bool(ty) {
+            quote! {
+                self.#name = match picker.id(#id).yes_or_no(#prompt, self.#name)? {
+                    Some(answer) => answer,
+                    None => return Ok(None),
+                };
+            }
+        } else {
+            quote! {
+                let parse = #parse;
+                let mut text = self.#name.to_string();
+                self.#name = loop {
+                    let Some(answer) = picker.id(#id).input(#prompt, &text)? else {
+                        return Ok(None);
+                    };
+                    match parse(&answer) {
+                        Ok(value) => break value,
+                        Err(e) if picker.id(#id).is_answered()? => return Err(e),
+                        Err(_) => text = answer,
+                    }
+                };
+            }
+        };
+        steps.push(step);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #path::Form for #ident #ty_generics #where_clause {
+            fn prompt_with(mut self, picker: &mut #path::Picker) -> #path::Result<Option<Self>> {
+                #(#steps)*
+                Ok(Some(self))
+            }
+
+            fn prompt_in(mut self, picker: &mut #path::Session<'_>) -> #path::Result<Option<Self>> {
+                #(#steps)*
+                Ok(Some(self))
+            }
+        }
+    })
+}
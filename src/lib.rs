@@ -16,8 +16,7 @@
 //! 
 //! ```rust,no_run
 //! fn main() -> std::io::Result<()> {
-//!     let options = pick_a_boo::Options::from(&["Yes", "So so", "Maybe", "No"])
-//!         .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
+//!     let options = pick_a_boo::Options::from(&["Yes", "So so", "Maybe", "No"])?;
 //!     match pick_a_boo::choose("🦀 Do you like Rust? 🦀", options) {
 //!         Ok(Some(choice)) if choice == "Yes"   => println!("You like Rust! 🤩"),
 //!         Ok(Some(choice)) if choice == "So so" => println!("You feel so so about Rust."),
@@ -25,7 +24,7 @@
 //!         Ok(Some(choice)) if choice == "No"    => println!("You don't like Rust... 😭"),
 //!         Ok(Some(_)) => panic!("Unknown choice. never reach here!"),
 //!         Ok(None) => println!("Cancelled."),
-//!         Err(e) => return Err(std::io::Error::new(std::io::ErrorKind::Other, e)),
+//!         Err(e) => return Err(e.into()),
 //!     }
 //!     Ok(())
 //! }
@@ -44,15 +43,30 @@
 //! - Press Enter to select your choice.
 //! - Press the corresponding key (y/s/m/n) to select an option directly.
 //! - Press Ctrl+C or Escape to cancel (returns `None`).
+//!   To distinguish them, use [`Picker::choose_outcome`], which returns an [`Outcome`].
+//!
+//! ## Features
+//!
+//! - `async`: [`Picker::choose_async`] and its friends for tokio-based applications,
+//!   reading the key events without blocking the thread.
+//! - `ratatui`: [`PickerWidget`] rendering [`PickerState`] in ratatui applications.
+//! - `preseed-file`: the answers file (`Picker::answers_file`) giving the preseeded answers in TOML or JSON.
 
 use derive_builder::Builder;
 
 mod screen;
+mod render;
+mod preseed;
 mod routine;
 
 #[cfg(test)]
 extern crate self as pick_a_boo;
 
+/// The options of the render bench, also used by the unit tests.
+#[cfg(test)]
+#[path = "../benches/common/mod.rs"]
+mod bench_common;
+
 /// Macro to create an [Item] instance with flexible arguments.
 /// The first positional argument is the long name (label) of the item.
 /// Following arguments can be provided as either positional or named arguments:
@@ -131,6 +145,10 @@ extern crate self as pick_a_boo;
 /// ```
 pub use pick_a_boo_macros::item;
 
+/// Derive macro to implement the [Form] trait for a struct with named fields.
+/// See [Form] for details.
+pub use pick_a_boo_macros::Form;
+
 /// Item struct represents a selectable option with a name, key, and optional description.
 #[derive(Debug, Clone)]
 pub struct Item {
@@ -204,6 +222,39 @@ impl Item {
             Item::new_full(long_label, key.to_string(), key, description)
         }
     }
+
+    /// Create the items from the given labels taken as they are, that is, without parsing the descriptions and the short keys.
+    /// Each item gets a distinct key, the first character of the label not used by the previous items, converted to lowercase.
+    /// If all the characters are used, the key is the first unused digit or letter.
+    /// Once the digits and the letters run out as well, the rest of the items get distinct keys from the Unicode
+    /// private use area (U+E000 and after), which cannot be typed; such items are chosen by moving the cursor,
+    /// and their short labels are the labels themselves.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// use pick_a_boo::Item;
+    /// let items = Item::from_labels(&["MIT", "MPL-2.0", "Apache-2.0"]);
+    /// assert_eq!(items.iter().map(|item| item.key).collect::<String>(), "mpa");
+    /// ```
+    pub fn from_labels<S: AsRef<str>>(labels: &[S]) -> Vec<Self> {
+        let mut used = std::collections::HashSet::new();
+        let mut untypable = ('\u{e000}'..='\u{f8ff}').map(|key| (key, true));
+        labels.iter().map(|label| {
+            let label = label.as_ref();
+            let (key, private) = label.chars()
+                .filter(|c| c.is_alphanumeric())
+                .map(|c| c.to_ascii_lowercase())
+                .chain(('1'..='9').chain('a'..='z'))
+                .find(|c| !used.contains(c))
+                .map(|key| (key, false))
+                .or_else(|| untypable.next())
+                .unwrap_or(('\0', true));
+            used.insert(key);
+            let short_label = if private { label.to_string() } else { key.to_string() };
+            Item::new_full(label, short_label.as_str(), key, None)
+        }).collect()
+    }
 }
 
 impl From<&str> for Item {
@@ -218,7 +269,209 @@ impl From<String> for Item {
     }
 }
 
-type ErrBox = Box<dyn std::error::Error + Send + Sync>;
+/// Error enum represents the errors of this library.
+/// Callers can match on the variants to produce their own messages.
+///
+/// ```rust
+/// use pick_a_boo::{Error, Options};
+/// match Options::from(&["Yes", "Yes, sure", "No"]) {
+///     Err(Error::DuplicateKey { key, first, second }) => {
+///         println!("items #{first} and #{second} share the key '{key}'");
+///     }
+///     Err(e) => println!("error: {e}"),
+///     Ok(_) => println!("ok"),
+/// }
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// No items are given to the [Options].
+    EmptyItems,
+    /// The current index of the [Options] is out of bounds of the items.
+    CurrentOutOfBounds { index: usize, len: usize },
+    /// Two items have the same key. `first` and `second` are the indices of them.
+    DuplicateKey { key: char, first: usize, second: usize },
+    /// A required field of the builder is not set.
+    UninitializedField(&'static str),
+    /// The answer of the prompt cannot be converted into the value (e.g., by [Form]).
+    InvalidAnswer { prompt: String, answer: String, reason: String },
+    /// The preseeded answer for the prompt id ([Picker::id]) matches no item.
+    InvalidPreseed { id: String, answer: String, reason: String },
+    /// The answers file ([Picker::answers_file]) cannot be read or parsed.
+    #[cfg(feature = "preseed-file")]
+    AnswersFile { path: std::path::PathBuf, reason: String },
+    /// The prompt needs the interaction, but [`Policy::FailIfInteractive`] is set.
+    InteractionRequired { prompt: String },
+    /// The template of the prompt line ([Picker::template]) is invalid.
+    InvalidTemplate { template: String, reason: String },
+    /// stdin or stdout is not a TTY, so interactive input is unavailable.
+    NotATty,
+    /// I/O error from the terminal.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::EmptyItems => write!(f, "items cannot be empty"),
+            Error::CurrentOutOfBounds { index, len } => write!(f, "{index}: current index is out of bounds (len: {len})"),
+            Error::DuplicateKey { key, first, second } => write!(f, "{key}: duplicate key found (items #{first} and #{second})"),
+            Error::UninitializedField(name) => write!(f, "{name} must be set"),
+            Error::InvalidAnswer { prompt, answer, reason } => write!(f, "{prompt}: invalid answer \"{answer}\" ({reason})"),
+            Error::InvalidPreseed { id, answer, reason } => write!(f, "{id}: invalid preseeded answer \"{answer}\" ({reason})"),
+            #[cfg(feature = "preseed-file")]
+            Error::AnswersFile { path, reason } => write!(f, "{}: cannot read the answers file ({reason})", path.display()),
+            Error::InteractionRequired { prompt } => write!(f, "{prompt}: interaction is required, but not allowed by the policy"),
+            Error::InvalidTemplate { template, reason } => write!(f, "{template}: invalid template ({reason})"),
+            Error::NotATty => write!(f, "not running on a TTY (interactive input is unavailable)"),
+            Error::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<derive_builder::UninitializedFieldError> for Error {
+    fn from(e: derive_builder::UninitializedFieldError) -> Self {
+        Error::UninitializedField(e.field_name())
+    }
+}
+
+/// Convert into [std::io::Error] for the callers returning `std::io::Result`.
+/// [Error::Io] is unwrapped, and the others are wrapped with [std::io::Error::other].
+impl From<Error> for std::io::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Io(e) => e,
+            e => std::io::Error::other(e),
+        }
+    }
+}
+
+/// Result type of this library.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Outcome enum represents how a prompt is finished.
+/// [`Picker::choose`] and its friends fold every variant except [`Outcome::Selected`] into `None`,
+/// and [`Picker::choose_outcome`] and its friends return this enum for distinguishing them.
+///
+/// ```rust,no_run
+/// use pick_a_boo::{CancelReason, Options, Outcome, Picker};
+/// let options = Options::from(&["Yes", "No"]).unwrap();
+/// match Picker::default().choose_outcome("Continue?", options) {
+///     Ok(Outcome::Selected(choice)) => println!("You chose: {choice}"),
+///     Ok(Outcome::Cancelled(CancelReason::Escape)) => println!("Go back."),
+///     Ok(Outcome::Interrupted) => std::process::exit(130),
+///     Ok(Outcome::Eof) => println!("End of input."),
+///     Ok(_) => println!("Not selected."),
+///     Err(e) => eprintln!("Error: {e}"),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Outcome<T> {
+    /// The user selected (or entered) the value with Enter.
+    Selected(T),
+    /// The user cancelled the prompt.
+    Cancelled(CancelReason),
+    /// The user pressed Ctrl+C.
+    /// If [`Picker::reraise_interrupt`] is `true`, SIGINT is raised after restoring the terminal.
+    Interrupted,
+    /// The user pressed Ctrl+D.
+    /// In the text input ([`Picker::input`]), Ctrl+D is treated as EOF only when the line is empty.
+    Eof,
+    /// The countdown of [`Picker::timeout`] expired with [`OnTimeout::TimedOut`].
+    TimedOut,
+    /// The prompt was dismissed by [`CancelToken::cancel`] from outside, e.g., another thread.
+    Aborted,
+}
+
+/// CancelReason enum represents why the prompt is cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CancelReason {
+    /// The user pressed Escape.
+    Escape,
+}
+
+impl<T> Outcome<T> {
+    /// Returns the selected value, or `None` for the other outcomes.
+    pub fn selected(self) -> Option<T> {
+        match self {
+            Outcome::Selected(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Maps the selected value with the given function, and keeps the other outcomes as they are.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Outcome<U> {
+        match self {
+            Outcome::Selected(value) => Outcome::Selected(f(value)),
+            Outcome::Cancelled(reason) => Outcome::Cancelled(reason),
+            Outcome::Interrupted => Outcome::Interrupted,
+            Outcome::Eof => Outcome::Eof,
+            Outcome::TimedOut => Outcome::TimedOut,
+            Outcome::Aborted => Outcome::Aborted,
+        }
+    }
+}
+
+/// Policy enum defines how the prompts are answered, for bypassing them with `--yes` flags or in automation.
+/// The policy is set process-wide by [`set_policy`], or per [Picker] by [`Picker::policy`].
+/// The auto-answered prompts are logged at the info level.
+///
+/// ```rust
+/// // e.g., when the `--yes` flag is given.
+/// pick_a_boo::set_policy(pick_a_boo::Policy::AssumeYes);
+/// assert_eq!(pick_a_boo::yes_or_no("Overwrite?", false).unwrap(), Some(true));
+/// # pick_a_boo::set_policy(pick_a_boo::Policy::Interactive);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Prompts are asked interactively. Default.
+    Interactive,
+    /// Prompts are answered with the defaults without rendering, that is,
+    /// the current item of the [Options], `default_yes` of yes-or-no, and the initial text of the input.
+    AssumeDefault,
+    /// Same as [`Policy::AssumeDefault`], but yes-or-no questions are answered with "Yes".
+    AssumeYes,
+    /// Same as [`Policy::AssumeDefault`], but yes-or-no questions are answered with "No".
+    AssumeNo,
+    /// Prompts return [`Error::InteractionRequired`] instead of asking.
+    FailIfInteractive,
+}
+
+static POLICY: std::sync::atomic::AtomicU8 = std::sync::atomic::AtomicU8::new(0);
+
+impl Policy {
+    const ALL: [Policy; 5] = [
+        Policy::Interactive, Policy::AssumeDefault, Policy::AssumeYes, Policy::AssumeNo, Policy::FailIfInteractive,
+    ];
+}
+
+/// Set the process-wide [Policy] for the [Picker]s without [`Picker::policy`].
+pub fn set_policy(policy: Policy) {
+    log::info!("Setting the process-wide policy: {policy:?}");
+    let index = Policy::ALL.iter().position(|p| *p == policy).unwrap_or(0);
+    POLICY.store(index as u8, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Returns the process-wide [Policy] set by [`set_policy`]. Default is [`Policy::Interactive`].
+pub fn policy() -> Policy {
+    Policy::ALL[POLICY.load(std::sync::atomic::Ordering::SeqCst) as usize]
+}
 
 /// Options struct holds a list of items and the current selection index.
 /// To create an instance, use the `OptionBuilder` or the [`Options::from`] helper method.
@@ -252,12 +505,12 @@ type ErrBox = Box<dyn std::error::Error + Send + Sync>;
 /// ### Errors
 /// 
 /// The builder will return an error if:
-/// - No items are provided.
-/// - The current index is out of bounds.
-/// - There are duplicate keys among the items.
+/// - No items are provided ([`Error::EmptyItems`]).
+/// - The current index is out of bounds ([`Error::CurrentOutOfBounds`]).
+/// - There are duplicate keys among the items ([`Error::DuplicateKey`]).
 /// 
 #[derive(Debug, Builder)]
-#[builder(build_fn(validate = "validate_options", error = "ErrBox"))]
+#[builder(build_fn(validate = "validate_options", error = "Error"))]
 pub struct Options {
     #[builder(setter(each(name="item", into)))]
     items: Vec<Item>,
@@ -265,31 +518,32 @@ pub struct Options {
     current: usize,
 }
 
-fn validate_options(options: &OptionsBuilder) -> Result<(), ErrBox> {
-    let items = options.items.as_ref().ok_or("items must be set")?;
+fn validate_options(options: &OptionsBuilder) -> Result<()> {
+    let items = options.items.as_ref().ok_or(Error::EmptyItems)?;
     let current = options.current.unwrap_or(0);
     validate_option_items(items, current)
 }
 
-fn validate_option_items(items: &[Item], current: usize) -> Result<(), ErrBox> {
+fn validate_option_items(items: &[Item], current: usize) -> Result<()> {
     if items.is_empty() {
-        return Err("items cannot be empty".into());
+        return Err(Error::EmptyItems);
     }
     if current >= items.len() {
-        return Err(format!("{current}: current index is out of bounds (len: {})", items.len()).into());
+        return Err(Error::CurrentOutOfBounds { index: current, len: items.len() });
     }
-    if let Some(key) = find_duplicate_keys(items) {
-        return Err(format!("{key}: duplicate key found").into());
+    if let Some((key, first, second)) = find_duplicate_keys(items) {
+        return Err(Error::DuplicateKey { key, first, second });
     }
     Ok(())
 }
 
-fn find_duplicate_keys(items: &[Item]) -> Option<char> {
-    use std::collections::HashSet;
-    let mut keys = HashSet::new();
-    for item in items {
-        if !keys.insert(item.key) {
-            return Some(item.key);
+/// Returns the duplicated key and the indices of the first and second items having it.
+fn find_duplicate_keys(items: &[Item]) -> Option<(char, usize, usize)> {
+    use std::collections::HashMap;
+    let mut keys = HashMap::new();
+    for (index, item) in items.iter().enumerate() {
+        if let Some(first) = keys.insert(item.key, index) {
+            return Some((item.key, first, index));
         }
     }
     None
@@ -298,7 +552,7 @@ fn find_duplicate_keys(items: &[Item]) -> Option<char> {
 impl Options {
     /// Helper method to create Options instance from a slice of strings.
     /// Each item of the slice is converted with [`Item::parse`] method.
-    pub fn from<S: AsRef<str>>(items: &[S]) -> Result<Self, ErrBox> {
+    pub fn from<S: AsRef<str>>(items: &[S]) -> Result<Self> {
         let item_vec = items.iter().map(|s| Item::parse(s.as_ref())).collect::<Vec<_>>();
         validate_option_items(&item_vec, 0)?;
         Ok(Options {
@@ -347,11 +601,8 @@ impl Options {
         self.items[self.current].long_label.clone()
     }
 
-    fn update_current(self, index: usize) -> Self {
-        Self {
-            current: index,
-            ..self
-        }
+    fn update_current(&mut self, index: usize) {
+        self.current = index;
     }
 }
 
@@ -360,19 +611,98 @@ pub struct Display<'a, 'b>(&'a Options, &'b Picker);
 impl std::fmt::Display for Display<'_, '_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let picker = self.1;
+        let (left, right) = &picker.selected_decoration;
         let display = self.0.iter().enumerate()
-            .map(|(size, item)| {
-                if size == self.0.current {
-                    format!(" {} ", item.long_label)
-                } else {
-                    item.key.to_string()
+            .filter_map(|(index, item)| {
+                if index == self.0.current {
+                    return Some(format!("{left}{}{right}", item.long_label));
+                }
+                match picker.unselected_display {
+                    UnselectedDisplay::Key => Some(item.key.to_string()),
+                    UnselectedDisplay::ShortLabel => Some(item.short_label.clone()),
+                    UnselectedDisplay::LongLabel => Some(item.long_label.clone()),
+                    UnselectedDisplay::Hidden => None,
                 }
             }).collect::<Vec<_>>().join(&picker.delimiter);
         write!(f, "{display}")
     }
 }
 
+/// UnselectedDisplay enum defines how the items other than the current one are displayed in the prompt line.
+/// The current item is always displayed with its long label, decorated with [`Picker::selected_decoration`].
+///
+/// The following examples use the options in [`DescriptionShowMode`] with "Maybe" selected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnselectedDisplay {
+    /// Items are displayed with their keys. Default mode.
+    ///
+    /// ```text
+    /// Do you like Rust?  y/s/ Maybe /n
+    /// ```
+    Key,
+    /// Items are displayed with their short labels.
+    /// If "So so" is defined with `short = "So"`, it is displayed as follows:
+    ///
+    /// ```text
+    /// Do you like Rust?  y/So/ Maybe /n
+    /// ```
+    ShortLabel,
+    /// Items are displayed with their long labels.
+    ///
+    /// ```text
+    /// Do you like Rust?  Yes/So so/ Maybe /No
+    /// ```
+    LongLabel,
+    /// Items are not displayed, only the current item is shown.
+    ///
+    /// ```text
+    /// Do you like Rust?  Maybe
+    /// ```
+    Hidden,
+}
+
+/// Layout enum defines how the items are arranged.
+/// The following examples use the options in [`DescriptionShowMode`] with "Maybe" selected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// Items are placed in the prompt line. Default layout.
+    ///
+    /// ```text
+    /// Do you like Rust?  y/s/ Maybe /n
+    /// ```
+    Inline,
+    /// The prompt is on its own line, and each item is a row.
+    /// Descriptions are shown in the rows according to [`DescriptionShowMode`].
+    /// Up/Down keys move the current item as well as Left/Right keys.
+    ///
+    /// ```text
+    /// Do you like Rust?
+    ///   [y] Yes   — I love it
+    ///   [s] So so — I like it, but sometimes it's hard
+    /// > [m] Maybe — I haven't tried it yet
+    ///   [n] No    — I don't like it
+    /// ```
+    List,
+    /// The prompt is on its own line, and the items are placed in columns fitting in the terminal width.
+    /// Up/Down keys move to the item in the previous/next row.
+    /// The description of the current item is shown below the grid unless [`DescriptionShowMode::Never`].
+    ///
+    /// ```text
+    /// Do you like Rust?
+    ///   [y] Yes      [s] So so
+    /// > [m] Maybe    [n] No
+    /// ```
+    Grid,
+    /// The layout is chosen by the number of items and the terminal width:
+    /// [`Layout::Inline`] for five or fewer items fitting in a row,
+    /// [`Layout::Grid`] for the items without descriptions placed in two or more columns,
+    /// and [`Layout::List`] otherwise.
+    Auto,
+}
+
 /// DescriptionShowMode enum defines how item descriptions are displayed.
+/// Descriptions longer than the terminal width, or containing line breaks (`\n`), are shown in multiple lines
+/// indented to the column of the descriptions.
 /// 
 /// ### Example
 /// 
@@ -442,6 +772,550 @@ pub enum DescriptionNameWidth {
     Auto,
 }
 
+/// Summary struct defines the line replacing the picker after answering.
+/// See [`Picker::summary`].
+///
+/// ```text
+/// ✔ Do you like Rust? · Yes
+/// ✖ Do you like Rust? · cancelled
+/// ```
+#[derive(Debug, Clone)]
+pub struct Summary {
+    /// Format of the line for the selected answer.
+    /// `{prompt}` and `{answer}` are replaced with the prompt and the answer.
+    /// Default is `"✔ {prompt} · {answer}"`.
+    pub selected: String,
+    /// Format of the line if no answer is selected (cancelled, interrupted, or end of input).
+    /// `{prompt}` is replaced with the prompt.
+    /// Default is `"✖ {prompt} · cancelled"`.
+    pub cancelled: String,
+}
+
+impl Summary {
+    /// Create a Summary instance with the formats for the selected answer and for no answer.
+    pub fn new<S: Into<String>, T: Into<String>>(selected: S, cancelled: T) -> Self {
+        Summary { selected: selected.into(), cancelled: cancelled.into() }
+    }
+}
+
+impl Default for Summary {
+    fn default() -> Self {
+        Summary::new("✔ {prompt} · {answer}", "✖ {prompt} · cancelled")
+    }
+}
+
+/// InvalidKeyFeedback enum defines the feedback for the key matching no item.
+/// See [`Picker::invalid_key_feedback`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidKeyFeedback {
+    /// No feedback. Default.
+    Silent,
+    /// Ring the terminal bell.
+    Bell,
+    /// Flash the picker in reverse video for a moment.
+    Flash,
+    /// Show the transient message below the picker, until another key is pressed or a few seconds have passed.
+    /// `{key}` in the message is replaced with the pressed key.
+    Message(String),
+}
+
+impl InvalidKeyFeedback {
+    /// Returns [`InvalidKeyFeedback::Message`] with the default message, `'{key}' is not an option`.
+    pub fn message() -> Self {
+        InvalidKeyFeedback::Message("'{key}' is not an option".to_string())
+    }
+}
+
+/// Footer struct defines the hint line listing the available keys below the options.
+/// The line is generated from the keys active in the current layout, such as
+/// `←/→ move · enter select · esc cancel · y/s/m/n jump · ? help`, and the labels can be localized.
+/// The help overlay shown by the `?` key uses the same labels for the same keys, even without the footer line.
+/// See [`Picker::footer`].
+///
+/// ```rust
+/// use pick_a_boo::{Footer, PickerBuilder};
+/// let footer = Footer { move_label: "移動".to_string(), ..Footer::default() };
+/// let picker = PickerBuilder::default()
+///     .footer(footer)
+///     .build().expect("Failed to build Picker");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Footer {
+    /// Label for the keys moving the current item. Default is `"move"`.
+    pub move_label: String,
+    /// Label for the Enter key. Default is `"select"`.
+    pub select_label: String,
+    /// Label for the Esc key. Default is `"cancel"`.
+    pub cancel_label: String,
+    /// Label for the item keys. Default is `"jump"`.
+    pub jump_label: String,
+    /// Label for the keys scrolling the preview pane, shown only with [`Picker::preview`]. Default is `"scroll"`.
+    pub scroll_label: String,
+    /// Label for the `?` key showing the help, shown only if `?` is not an item key. Default is `"help"`.
+    pub help_label: String,
+    /// Separator between the hints. Default is `" · "`.
+    pub separator: String,
+    /// Fixed text shown instead of the generated hints. Default is `None`.
+    pub text: Option<String>,
+}
+
+impl Footer {
+    /// Create a Footer instance showing the given fixed text instead of the generated hints.
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Footer { text: Some(text.into()), ..Footer::default() }
+    }
+}
+
+impl Default for Footer {
+    fn default() -> Self {
+        Footer {
+            move_label: "move".to_string(),
+            select_label: "select".to_string(),
+            cancel_label: "cancel".to_string(),
+            jump_label: "jump".to_string(),
+            scroll_label: "scroll".to_string(),
+            help_label: "help".to_string(),
+            separator: " · ".to_string(),
+            text: None,
+        }
+    }
+}
+
+/// CancelToken is the handle dismissing the open prompt from outside, e.g., when a background job finishes.
+/// The token is checked between the polls of the terminal events, and then the prompt finishes with
+/// [`Outcome::Aborted`] after restoring the terminal. The clones share the same state.
+/// Once cancelled, the token stays cancelled, and the later prompts with it are aborted immediately.
+/// See [`Picker::cancel_token`].
+///
+/// ```rust,no_run
+/// use pick_a_boo::{CancelToken, Options, Outcome, PickerBuilder};
+/// let token = CancelToken::new();
+/// let mut picker = PickerBuilder::default()
+///     .cancel_token(token.clone())
+///     .build().expect("Failed to build Picker");
+/// std::thread::spawn(move || {
+///     std::thread::sleep(std::time::Duration::from_secs(5)); // e.g., the job finishes
+///     token.cancel();
+/// });
+/// let outcome = picker.choose_outcome("Stop the job?", Options::from(&["Yes", "No"]).unwrap());
+/// assert!(matches!(outcome, Ok(Outcome::Aborted) | Ok(Outcome::Selected(_))));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+impl CancelToken {
+    /// Create a CancelToken instance not cancelled yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the prompts with this token.
+    pub fn cancel(&self) {
+        log::info!("Cancelling the prompt by the token");
+        self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Returns `true` if [`CancelToken::cancel`] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+/// Share the existing flag as the token, and then storing `true` to the flag cancels the prompt.
+impl From<std::sync::Arc<std::sync::atomic::AtomicBool>> for CancelToken {
+    fn from(flag: std::sync::Arc<std::sync::atomic::AtomicBool>) -> Self {
+        CancelToken(flag)
+    }
+}
+
+/// Printer is the handle printing lines above the open picker, for the background tasks reporting while
+/// waiting for the user, like `indicatif::ProgressBar::println` and `indicatif::ProgressBar::suspend`.
+/// While a picker with this handle is open in the normal screen, the printed lines are put above the picker
+/// between the polls of the terminal events, and the picker is redrawn beneath them.
+/// In the alternate screen mode, the lines are printed after leaving the screen.
+/// Between the prompts of a [Session] in the normal screen, the lines are printed right away.
+/// Otherwise, the lines are printed to stdout immediately. The clones share the same picker.
+/// See [`Picker::printer`].
+///
+/// Printer also implements [`std::io::Write`], then the complete lines are printed as [`Printer::println`] does,
+/// e.g., for the log output.
+///
+/// ```rust,no_run
+/// use pick_a_boo::{Options, PickerBuilder, Printer};
+/// let printer = Printer::new();
+/// let mut picker = PickerBuilder::default()
+///     .printer(printer.clone())
+///     .build().expect("Failed to build Picker");
+/// std::thread::spawn(move || {
+///     for i in 1..=3 {
+///         printer.println(format!("downloaded {i}/3"));
+///         std::thread::sleep(std::time::Duration::from_secs(1));
+///     }
+///     printer.suspend(|| println!("all downloaded"));
+/// });
+/// let answer = picker.choose("Install after downloading?", Options::from(&["Yes", "No"]).unwrap());
+/// ```
+#[derive(Debug, Default)]
+pub struct Printer {
+    shared: std::sync::Arc<PrinterShared>,
+    /// The incomplete line written by [std::io::Write], printed when the line is completed.
+    partial: Vec<u8>,
+}
+
+#[derive(Debug, Default)]
+struct PrinterShared {
+    state: std::sync::Mutex<PrinterState>,
+    /// Notified when the target or the suspension changes.
+    changed: std::sync::Condvar,
+}
+
+#[derive(Debug, Default)]
+struct PrinterState {
+    target: PrintTarget,
+    /// The lines queued while a picker is shown, printed above it.
+    pending: Vec<String>,
+    suspension: Suspension,
+}
+
+/// PrintTarget is where the printed lines go now.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum PrintTarget {
+    /// No picker is open, then the lines are printed to stdout.
+    #[default]
+    Stdout,
+    /// A picker with this handle is open, then the lines are queued in `pending`.
+    Picker,
+    /// A session is between the prompts, then the lines are printed in the raw mode in the normal screen,
+    /// or queued in the alternate screen mode.
+    /// `line_open` is whether the cursor is at the end of a line, then the next line starts with a line break.
+    Session { alternate: bool, line_open: bool },
+}
+
+/// Suspension is the progress of [`Printer::suspend`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Suspension {
+    #[default]
+    Off,
+    /// Waiting for the picker to hide itself at the next poll.
+    Requested,
+    /// The closure is running, and the picker waits for it.
+    Granted,
+}
+
+impl Printer {
+    /// Create a Printer instance not attached to any picker yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Print the line above the open picker, or to stdout if no picker is open.
+    pub fn println<S: Into<String>>(&self, line: S) {
+        let line = line.into();
+        let mut state = self.lock();
+        match state.target {
+            PrintTarget::Stdout => println!("{line}"),
+            PrintTarget::Picker | PrintTarget::Session { alternate: true, .. } => state.pending.push(line),
+            PrintTarget::Session { alternate: false, line_open } => {
+                print_raw(&[line], line_open);
+                state.target = PrintTarget::Session { alternate: false, line_open: true };
+            }
+        }
+    }
+
+    /// Hide the open picker while the given closure runs, e.g., for writing to the terminal,
+    /// and then the picker is redrawn below the output. Returns the result of the closure.
+    /// The terminal is in the normal mode (not raw mode, and not in the alternate screen) during the closure.
+    ///
+    /// This method blocks the thread until the picker hides itself at the next poll of the terminal events,
+    /// so call it from another thread than the one running the picker, e.g., by `tokio::task::spawn_blocking`.
+    /// If no picker is open, the closure runs immediately.
+    pub fn suspend<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        let mut state = self.wait_while(self.lock(), |state| state.suspension != Suspension::Off);
+        state.suspension = Suspension::Requested;
+        // the screen mode to release here between the prompts of a session, since no picker does it.
+        let session = loop {
+            match state.target {
+                PrintTarget::Stdout => {
+                    state.suspension = Suspension::Off;
+                    self.shared.changed.notify_all();
+                    drop(state);
+                    return f();
+                }
+                PrintTarget::Session { alternate, line_open } => {
+                    // move to the next line for the output of the closure.
+                    if line_open && !alternate {
+                        print_raw(&[""], true);
+                    }
+                    state.target = PrintTarget::Session { alternate, line_open: false };
+                    state.suspension = Suspension::Granted;
+                    break Some(alternate);
+                }
+                PrintTarget::Picker if state.suspension == Suspension::Granted => break None,
+                PrintTarget::Picker => {
+                    state = self.shared.changed.wait(state).unwrap_or_else(std::sync::PoisonError::into_inner);
+                }
+            }
+        };
+        drop(state);
+        if let Some(alternate) = session
+            && let Err(e) = screen::release(alternate)
+        {
+            log::warn!("Failed to release the screen: {e}");
+        }
+        let result = f();
+        if let Some(alternate) = session
+            && let Err(e) = screen::reacquire(alternate)
+        {
+            log::warn!("Failed to re-enter the screen: {e}");
+        }
+        self.lock().suspension = Suspension::Off;
+        self.shared.changed.notify_all();
+        result
+    }
+
+    /// Mark the picker open or closed. The queued lines are printed when the picker is closed.
+    pub(crate) fn set_active(&self, active: bool) {
+        let mut state = self.lock();
+        if active {
+            state.target = PrintTarget::Picker;
+        } else {
+            state.target = PrintTarget::Stdout;
+            for line in state.pending.drain(..) {
+                println!("{line}");
+            }
+        }
+        self.shared.changed.notify_all();
+    }
+
+    /// Mark the session between the prompts, and print the queued lines right away in the normal screen.
+    /// `line_open` is whether the cursor is at the end of the last prompt.
+    pub(crate) fn pause(&self, alternate: bool, line_open: bool) {
+        let mut state = self.lock();
+        let mut line_open = line_open;
+        if !alternate {
+            let pending = std::mem::take(&mut state.pending);
+            print_raw(&pending, line_open);
+            line_open |= !pending.is_empty();
+        }
+        state.target = PrintTarget::Session { alternate, line_open };
+        self.shared.changed.notify_all();
+    }
+
+    /// Mark the next prompt of the session shown, after the running [Printer::suspend] finishes.
+    /// Returns whether the cursor is at the end of a line, if the session has been paused.
+    pub(crate) fn resume(&self) -> Option<bool> {
+        let mut state = self.wait_while(self.lock(), |state| state.suspension == Suspension::Granted);
+        let PrintTarget::Session { line_open, .. } = state.target else {
+            return None;
+        };
+        state.target = PrintTarget::Picker;
+        Some(line_open)
+    }
+
+    /// Returns the lines queued since the last call.
+    pub(crate) fn take_pending(&self) -> Vec<String> {
+        std::mem::take(&mut self.lock().pending)
+    }
+
+    /// Returns `true` if [Printer::suspend] waits for the picker to hide itself.
+    pub(crate) fn suspend_requested(&self) -> bool {
+        self.lock().suspension == Suspension::Requested
+    }
+
+    /// Let the closure of [Printer::suspend] run, and wait until it returns.
+    /// The picker must be hidden before calling this method.
+    pub(crate) fn hand_over(&self) {
+        let mut state = self.lock();
+        if state.suspension != Suspension::Requested {
+            return;
+        }
+        state.suspension = Suspension::Granted;
+        self.shared.changed.notify_all();
+        drop(self.wait_while(state, |state| state.suspension == Suspension::Granted));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PrinterState> {
+        // the state stays consistent even if another thread panicked while printing.
+        self.shared.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn wait_while<'a, F: FnMut(&mut PrinterState) -> bool>(
+        &self,
+        state: std::sync::MutexGuard<'a, PrinterState>,
+        condition: F,
+    ) -> std::sync::MutexGuard<'a, PrinterState> {
+        self.shared.changed.wait_while(state, condition).unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Print the lines in the raw mode, with a line break before each line if the cursor is at the end of a line.
+/// The cursor stays at the end of the last line.
+fn print_raw<S: AsRef<str>>(lines: &[S], line_open: bool) {
+    use std::io::Write;
+    let mut stdout = std::io::stdout();
+    for (index, line) in lines.iter().enumerate() {
+        let separator = if line_open || index > 0 { "\r\n" } else { "" };
+        let _ = write!(stdout, "{separator}{}", line.as_ref());
+    }
+    let _ = stdout.flush();
+}
+
+impl Clone for Printer {
+    fn clone(&self) -> Self {
+        Printer { shared: std::sync::Arc::clone(&self.shared), partial: vec![] }
+    }
+}
+
+impl std::io::Write for Printer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.partial.extend_from_slice(buf);
+        while let Some(index) = self.partial.iter().position(|b| *b == b'\n') {
+            let line = self.partial.drain(..=index).collect::<Vec<_>>();
+            let line = String::from_utf8_lossy(&line);
+            self.println(line.trim_end_matches(['\r', '\n']));
+        }
+        Ok(buf.len())
+    }
+
+    /// Print the incomplete line, if any.
+    fn flush(&mut self) -> std::io::Result<()> {
+        if !self.partial.is_empty() {
+            let line = String::from_utf8_lossy(&self.partial).into_owned();
+            self.partial.clear();
+            self.println(line);
+        }
+        Ok(())
+    }
+}
+
+/// OnTimeout enum defines the outcome when the countdown of [Timeout] expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnTimeout {
+    /// The current item is selected, as if Enter was pressed. Default.
+    SelectCurrent,
+    /// The prompt finishes with [`Outcome::TimedOut`].
+    TimedOut,
+}
+
+/// Timeout struct shows a live countdown below the options, and finishes the prompt when the countdown expires,
+/// like the boot menus. Any key press stops the countdown, and then the prompt waits for the user as usual.
+/// The countdown is available for choosing the options, and ignored by [`Picker::input`].
+/// See [`Picker::timeout`].
+///
+/// ```rust
+/// use pick_a_boo::{OnTimeout, PickerBuilder, Timeout};
+/// use std::time::Duration;
+/// let picker = PickerBuilder::default()
+///     .timeout(Timeout::new(Duration::from_secs(10))
+///         .on_timeout(OnTimeout::SelectCurrent)
+///         .message("Booting {answer} in {seconds}s"))
+///     .build().expect("Failed to build Picker");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timeout {
+    /// Duration of the countdown from the start of the prompt.
+    pub duration: std::time::Duration,
+    /// Outcome when the countdown expires. Default is [`OnTimeout::SelectCurrent`].
+    pub on_timeout: OnTimeout,
+    /// Format of the countdown line.
+    /// `{answer}` and `{seconds}` are replaced with the long label of the current item and the remaining seconds.
+    /// Default is `"{answer} in {seconds}s, press any key to stop the countdown"`.
+    pub message: String,
+}
+
+impl Timeout {
+    /// Create a Timeout instance with the given duration, selecting the current item on expiry.
+    pub fn new(duration: std::time::Duration) -> Self {
+        Timeout {
+            duration,
+            on_timeout: OnTimeout::SelectCurrent,
+            message: "{answer} in {seconds}s, press any key to stop the countdown".to_string(),
+        }
+    }
+
+    /// Set the outcome when the countdown expires.
+    pub fn on_timeout(mut self, on_timeout: OnTimeout) -> Self {
+        self.on_timeout = on_timeout;
+        self
+    }
+
+    /// Set the format of the countdown line.
+    pub fn message<S: Into<String>>(mut self, message: S) -> Self {
+        self.message = message.into();
+        self
+    }
+}
+
+/// PreviewPosition enum defines where the preview pane is placed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewPosition {
+    /// The pane is placed below the options, separated by a horizontal line. Default position.
+    Below,
+    /// The pane is placed to the right of the options, separated by a vertical line.
+    Beside,
+}
+
+/// Preview struct shows the output of a callback for the current item in a pane, like `fzf --preview`.
+/// The callback is called whenever the current item changes, and its output is split into lines.
+/// The pane scrolls independently with Shift+Up/Shift+Down and PageUp/PageDown.
+/// See [`Picker::preview`].
+///
+/// ```rust
+/// use pick_a_boo::{PickerBuilder, Preview, PreviewPosition};
+/// let picker = PickerBuilder::default()
+///     .preview(Preview::new(|item| format!("You are choosing {}", item.long_label))
+///         .position(PreviewPosition::Below)
+///         .height(5))
+///     .build().expect("Failed to build Picker");
+/// ```
+///
+/// ```text
+/// Do you like Rust?  Yes /s/m/n
+/// ──────────────────────────────
+/// You are choosing Yes
+/// ```
+#[derive(Clone)]
+pub struct Preview {
+    callback: std::sync::Arc<dyn Fn(&Item) -> String + Send + Sync>,
+    /// Where the pane is placed. Default is [`PreviewPosition::Below`].
+    pub position: PreviewPosition,
+    /// The maximum number of rows of the pane. Default is 10.
+    pub height: usize,
+}
+
+impl Preview {
+    /// Create a Preview instance with the callback building the text for the given item.
+    pub fn new<F: Fn(&Item) -> String + Send + Sync + 'static>(callback: F) -> Self {
+        Preview { callback: std::sync::Arc::new(callback), position: PreviewPosition::Below, height: 10 }
+    }
+
+    /// Set the position of the pane.
+    pub fn position(mut self, position: PreviewPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the maximum number of rows of the pane.
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Returns the lines of the preview for the given item.
+    fn lines(&self, item: &Item) -> Vec<String> {
+        (self.callback)(item).lines().map(str::to_string).collect()
+    }
+}
+
+impl std::fmt::Debug for Preview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Preview")
+            .field("position", &self.position)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Picker struct is the main interface for choosing options.
 /// It holds the following configuration for the picker behavior.
 /// 
@@ -455,7 +1329,7 @@ pub enum DescriptionNameWidth {
 ///   No     I don't like it
 /// ```
 #[derive(Debug, Builder)]
-#[builder(build_fn(error = "ErrBox"))]
+#[builder(build_fn(validate = "validate_picker", error = "Error"))]
 pub struct Picker {
     /// Delimiter string used to separate options in the display.
     /// Defaults to "/".
@@ -484,10 +1358,49 @@ pub struct Picker {
     /// Parentheses to enclose the options display.
     /// If `None`, no parentheses are used.
     /// If `Some((left, right))`, the options will be enclosed with the specified left and right strings.
+    /// Writing the parentheses in [Picker::template] (e.g., `"{prompt} [{options}]"`) gives the same result.
     /// 
     /// In the [`PickerBuilder`], use the `paren(AsRef<str>)` method to set this field.
     /// see [PickerBuilder::paren] for details.
     pub paren: Option<(String, String)>,
+    /// How the items other than the current one are displayed. Default is [`UnselectedDisplay::Key`].
+    /// see [`UnselectedDisplay`] for details.
+    #[builder(default = UnselectedDisplay::Key)]
+    pub unselected_display: UnselectedDisplay,
+    #[builder(default = (" ".to_string(), " ".to_string()), setter(custom))]
+    /// Strings placed before and after the long label of the current item.
+    /// Default is `(" ", " ")`.
+    ///
+    /// In the [`PickerBuilder`], use the `selected_decoration(left, right)` method to set this field.
+    /// The following example is using `("[", "]")`:
+    ///
+    /// ```text
+    /// Do you like Rust? y/s/[Maybe]/n
+    /// ```
+    pub selected_decoration: (String, String),
+    /// Template of the prompt line. Default is `"{prompt} {options}"`.
+    /// The following placeholders are expanded, and `{{` and `}}` are the escaped braces.
+    /// Line breaks (`\n`) are also available.
+    ///
+    /// - `{prompt}`: the prompt given to [Picker::choose],
+    /// - `{options}`: the items enclosed with [Picker::paren], such as ` Yes /s/m/n`,
+    /// - `{default}`: the long label of the item current at the start of the prompt,
+    /// - `{description}`: the description of the current item, and
+    /// - `{hint}`: the keys of the items joined with [Picker::delimiter], such as `y/s/m/n`.
+    ///
+    /// The builder returns [`Error::InvalidTemplate`] for unknown placeholders or unbalanced braces.
+    /// The following example is using `"? {prompt}\n  [{options}] (default: {default})"`:
+    ///
+    /// ```text
+    /// ? Do you like Rust?
+    ///   [y/s/ Maybe /n] (default: Yes)
+    /// ```
+    #[builder(default = "{prompt} {options}".to_string(), setter(into))]
+    pub template: String,
+    /// Layout of the items. Default is [`Layout::Inline`].
+    /// see [`Layout`] for details.
+    #[builder(default = Layout::Inline)]
+    pub layout: Layout,
     /// Mode for showing item descriptions. Default is [`DescriptionShowMode::Never`].
     /// see [`DescriptionShowMode`] for details.
     #[builder(default = DescriptionShowMode::Never)]
@@ -497,6 +1410,84 @@ pub struct Picker {
     /// see [`DescriptionNameWidth`] for details.
     #[builder(default = DescriptionNameWidth::Auto, setter(into))]
     pub description_name_width: DescriptionNameWidth,
+    /// Summary line replacing the picker region after answering.
+    /// Default is `None`, that is, the last state of the picker remains on the screen.
+    /// If `Some`, the prompt line and the description lines are cleared, and the summary line is left instead.
+    /// In the alternate screen mode, the summary line is printed after leaving the alternate screen.
+    /// see [`Summary`] for details.
+    #[builder(default = None, setter(strip_option))]
+    pub summary: Option<Summary>,
+    /// Preview pane showing the output of a callback for the current item.
+    /// Default is `None`, that is, no preview pane.
+    /// see [`Preview`] for details.
+    #[builder(default = None, setter(strip_option))]
+    pub preview: Option<Preview>,
+    /// Footer line listing the available keys, shown below the options and the descriptions.
+    /// Default is `None`, that is, no footer line.
+    /// see [`Footer`] for details.
+    #[builder(default = None, setter(strip_option))]
+    pub footer: Option<Footer>,
+    /// Feedback for the key matching no item. Default is [`InvalidKeyFeedback::Silent`].
+    /// see [`InvalidKeyFeedback`] for details.
+    #[builder(default = InvalidKeyFeedback::Silent)]
+    pub invalid_key_feedback: InvalidKeyFeedback,
+    /// Countdown finishing the prompt without the user, for unattended-but-interruptible operations.
+    /// Default is `None`, that is, the prompt waits for the user forever.
+    /// see [`Timeout`] for details.
+    #[builder(default = None, setter(strip_option))]
+    pub timeout: Option<Timeout>,
+    /// Handle dismissing the open prompt from outside with [`Outcome::Aborted`].
+    /// Default is `None`.
+    /// see [`CancelToken`] for details.
+    #[builder(default = None, setter(strip_option, into))]
+    pub cancel_token: Option<CancelToken>,
+    /// Handle printing lines above the open picker from the other threads.
+    /// Default is `None`.
+    /// see [`Printer`] for details.
+    #[builder(default = None, setter(strip_option))]
+    pub printer: Option<Printer>,
+    /// Policy of answering the prompts of this picker.
+    /// Default is `None`, that is, the process-wide policy ([`set_policy`]) is used.
+    /// The preseeded answers ([`Picker::id`]) take precedence over the policy.
+    /// see [`Policy`] for details.
+    #[builder(default = None, setter(strip_option))]
+    pub policy: Option<Policy>,
+    /// Path of the answers file for the preseeded answers of the prompts with ids ([Picker::id])
+    /// (requires the `preseed-file` feature).
+    /// Default is `None`, and then the environment variable `PICK_A_BOO_ANSWERS_FILE` is used if set.
+    /// The file is a TOML table, or a JSON object if the extension is `.json`, mapping the ids to the answers.
+    ///
+    /// ```toml
+    /// rust-feeling = "Maybe"
+    /// publish = true
+    /// ```
+    #[cfg(feature = "preseed-file")]
+    #[builder(default = None, setter(strip_option, into))]
+    pub answers_file: Option<std::path::PathBuf>,
+    /// Whether to erase the picker completely after answering.
+    /// Default is `false`.
+    /// If `true`, all the lines drawn by the picker are cleared, and the cursor goes back to the beginning of
+    /// the line where the picker started, without switching to the alternate screen.
+    /// This is useful before printing machine-readable output.
+    /// [`Picker::summary`] is ignored in this mode.
+    #[builder(default = false)]
+    pub ephemeral: bool,
+    /// Whether to re-raise SIGINT when the user pressed Ctrl+C.
+    /// Default is `false`.
+    /// If `true`, SIGINT is raised after restoring the terminal, and then the process is terminated
+    /// as a shell would do (exit status 130), unless the caller handles SIGINT.
+    /// On non-Unix platforms, the process exits with the status 130.
+    #[builder(default = false)]
+    pub reraise_interrupt: bool,
+}
+
+fn validate_picker(picker: &PickerBuilder) -> Result<()> {
+    match &picker.template {
+        Some(template) => render::parse_template(template)
+            .map(|_| ())
+            .map_err(|reason| Error::InvalidTemplate { template: template.clone(), reason }),
+        None => Ok(()),
+    }
 }
 
 impl PickerBuilder {
@@ -530,6 +1521,15 @@ impl PickerBuilder {
     }
 }
 
+impl PickerBuilder {
+    /// Set the strings placed before and after the long label of the current item.
+    /// For example, `selected_decoration("[", "]")` displays the current item as `[Maybe]`.
+    pub fn selected_decoration<L: Into<String>, R: Into<String>>(&mut self, left: L, right: R) -> &mut Self {
+        self.selected_decoration = Some((left.into(), right.into()));
+        self
+    }
+}
+
 impl Default for Picker {
     /// Create a default Picker instance, it equivalent the following code.
     /// 
@@ -539,8 +1539,23 @@ impl Default for Picker {
     ///     .alternate_screen(false)
     ///     .allow_wrap(false)
     ///     // .paren("")  // None by default
+    ///     .unselected_display(pick_a_boo::UnselectedDisplay::Key)
+    ///     .selected_decoration(" ", " ")
+    ///     .template("{prompt} {options}")
+    ///     .layout(pick_a_boo::Layout::Inline)
     ///     .description_show_mode(pick_a_boo::DescriptionShowMode::Never)
     ///     .description_name_width(pick_a_boo::DescriptionNameWidth::Auto)
+    ///     // .summary(pick_a_boo::Summary::default())  // None by default
+    ///     // .preview(pick_a_boo::Preview::new(|item| ...))  // None by default
+    ///     // .footer(pick_a_boo::Footer::default())  // None by default
+    ///     .invalid_key_feedback(pick_a_boo::InvalidKeyFeedback::Silent)
+    ///     // .timeout(pick_a_boo::Timeout::new(std::time::Duration::from_secs(10)))  // None by default
+    ///     // .cancel_token(pick_a_boo::CancelToken::new())  // None by default
+    ///     // .printer(pick_a_boo::Printer::new())  // None by default
+    ///     // .policy(pick_a_boo::Policy::Interactive)  // None by default
+    ///     // .answers_file("answers.toml")  // None by default
+    ///     .ephemeral(false)
+    ///     .reraise_interrupt(false)
     ///     .build().expect("Failed to build Picker");
     /// ```
     fn default() -> Self {
@@ -553,32 +1568,504 @@ impl Default for Picker {
 impl Picker {
     /// Choose an option from the provided [Options] with the given prompt.
     /// Returns `Ok(Some(String))` for the selected option name, and `Ok(None)` if cancelled.
-    pub fn choose(&mut self, prompt: &str, options: Options) -> std::io::Result<Option<String>> {
+    pub fn choose(&mut self, prompt: &str, options: Options) -> Result<Option<String>> {
+        self.choose_outcome(prompt, options).map(Outcome::selected)
+    }
+
+    /// Choose an option from the provided [Options] with the given prompt.
+    /// Returns [`Outcome::Selected`] with the selected option name, or the other [`Outcome`] if not selected.
+    pub fn choose_outcome(&mut self, prompt: &str, options: Options) -> Result<Outcome<String>> {
         log::info!("Picker choosing with prompt: {prompt}");
-        routine::choose(self, prompt, options)
+        routine::choose(self, prompt, options, None)
     }
 
     /// Ask a yes-or-no question with the given prompt.
     /// The `default_yes` parameter determines the default selection.
     /// Returns `Ok(Some(true))` for "Yes", `Ok(Some(false))` for "No", and `Ok(None)` if cancelled.
-    pub fn yes_or_no(&mut self, prompt: &str, default_yes: bool) -> std::io::Result<Option<bool>> {
+    pub fn yes_or_no(&mut self, prompt: &str, default_yes: bool) -> Result<Option<bool>> {
+        self.yes_or_no_outcome(prompt, default_yes).map(Outcome::selected)
+    }
+
+    /// Ask a yes-or-no question with the given prompt.
+    /// Returns [`Outcome::Selected`] with `true` for "Yes" and `false` for "No", or the other [`Outcome`] if not selected.
+    pub fn yes_or_no_outcome(&mut self, prompt: &str, default_yes: bool) -> Result<Outcome<bool>> {
         log::info!("Picker yes_or_no with prompt: {prompt}");
-        let yes_item = Item::new_full("Yes", "y", 'y', None);
-        let no_item = Item::new_full("No", "n", 'n', None);
-        let options = OptionsBuilder::default()
-            .item(yes_item)
-            .item(no_item)
-            .current(if default_yes { 0 } else { 1 })
-            .build().map_err(std::io::Error::other)?;
-        let answer = self.choose(prompt, options);
-        match answer {
-            Ok(Some(choice)) if choice == "Yes" => Ok(Some(true)),
-            Ok(Some(choice)) if choice == "No" => Ok(Some(false)),
-            Ok(Some(_)) => Ok(None),
-            Ok(None) => Ok(None),
-            Err(e) => Err(e),
+        let options = yes_or_no_options(self.assumed_yes(default_yes))?;
+        let answer = self.choose_outcome(prompt, options)?;
+        Ok(answer.map(|choice| choice == "Yes"))
+    }
+
+    /// Choose an option as [`Picker::choose`] does, but without blocking the thread (requires the `async` feature).
+    /// The key events are read from the event stream of crossterm, and the futures run on the tokio runtime.
+    ///
+    /// The returned future is cancel-safe: dropping it (e.g., losing `tokio::select!`) restores the terminal.
+    ///
+    /// ```rust,no_run
+    /// # async fn run() -> pick_a_boo::Result<()> {
+    /// let mut picker = pick_a_boo::Picker::default();
+    /// let options = pick_a_boo::Options::from(&["Yes", "No"])?;
+    /// tokio::select! {
+    ///     answer = picker.choose_async("Deploy now?", options) => println!("{:?}", answer?),
+    ///     _ = tokio::time::sleep(std::time::Duration::from_secs(30)) => println!("No answer."),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn choose_async(&mut self, prompt: &str, options: Options) -> Result<Option<String>> {
+        self.choose_outcome_async(prompt, options).await.map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::choose_outcome`], but without blocking the thread (requires the `async` feature).
+    /// See [`Picker::choose_async`].
+    #[cfg(feature = "async")]
+    pub async fn choose_outcome_async(&mut self, prompt: &str, options: Options) -> Result<Outcome<String>> {
+        log::info!("Picker choosing asynchronously with prompt: {prompt}");
+        routine::choose_async(self, prompt, options, None).await
+    }
+
+    /// Same as [`Picker::yes_or_no`], but without blocking the thread (requires the `async` feature).
+    /// See [`Picker::choose_async`].
+    #[cfg(feature = "async")]
+    pub async fn yes_or_no_async(&mut self, prompt: &str, default_yes: bool) -> Result<Option<bool>> {
+        self.yes_or_no_outcome_async(prompt, default_yes).await.map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::yes_or_no_outcome`], but without blocking the thread (requires the `async` feature).
+    /// See [`Picker::choose_async`].
+    #[cfg(feature = "async")]
+    pub async fn yes_or_no_outcome_async(&mut self, prompt: &str, default_yes: bool) -> Result<Outcome<bool>> {
+        log::info!("Picker yes_or_no asynchronously with prompt: {prompt}");
+        let options = yes_or_no_options(self.assumed_yes(default_yes))?;
+        let answer = self.choose_outcome_async(prompt, options).await?;
+        Ok(answer.map(|choice| choice == "Yes"))
+    }
+
+    /// Ask a line of text with the given prompt.
+    /// The `initial` parameter is the initial text of the input, and it can be edited.
+    /// Returns `Ok(Some(String))` for the entered text, and `Ok(None)` if cancelled.
+    ///
+    /// The following keys are available for editing:
+    /// - Left/Right, Home/End (or Ctrl+A/Ctrl+E) to move the cursor,
+    /// - Backspace/Delete to remove a character,
+    /// - Ctrl+U/Ctrl+K to remove the text before/after the cursor,
+    /// - Enter to confirm, and Esc/Ctrl+C to cancel.
+    pub fn input(&mut self, prompt: &str, initial: &str) -> Result<Option<String>> {
+        self.input_outcome(prompt, initial).map(Outcome::selected)
+    }
+
+    /// Ask a line of text with the given prompt.
+    /// Returns [`Outcome::Selected`] with the entered text, or the other [`Outcome`] if not entered.
+    /// See [`Picker::input`] for the available keys.
+    pub fn input_outcome(&mut self, prompt: &str, initial: &str) -> Result<Outcome<String>> {
+        log::info!("Picker input with prompt: {prompt}");
+        routine::input(self, prompt, initial, None)
+    }
+
+    /// Start a [Session] running many prompts in one terminal session.
+    pub fn session(&mut self) -> Session<'_> {
+        log::info!("Starting a session");
+        Session { picker: self, screen: routine::SessionScreen::default() }
+    }
+
+    /// Give the stable id to the next prompt, for answering it with the preseeded answer.
+    /// If the answer for the id is given by the environment variable or the answers file,
+    /// the prompt returns it without touching the terminal.
+    ///
+    /// The environment variable is `PICK_A_BOO_ANSWER_<ID>`, where `<ID>` is the id in upper case,
+    /// and the characters other than ASCII letters and digits are replaced with `_`.
+    /// With the `preseed-file` feature, the answers file is given by `Picker::answers_file` or the environment variable
+    /// `PICK_A_BOO_ANSWERS_FILE`, and it is a TOML or JSON (by `.json` extension) table mapping the ids to the answers.
+    /// The environment variable takes precedence over the answers file.
+    ///
+    /// The answer is matched with the long labels, the keys, and the short labels of the items in this order,
+    /// and [`Error::InvalidPreseed`] is returned if no item matches.
+    ///
+    /// ```rust,no_run
+    /// // answered by `PICK_A_BOO_ANSWER_RUST_FEELING=Maybe`, or `rust-feeling = "Maybe"` in the answers file.
+    /// let options = pick_a_boo::Options::from(&["Yes", "So so", "Maybe", "No"]).unwrap();
+    /// let answer = pick_a_boo::Picker::default()
+    ///     .id("rust-feeling")
+    ///     .choose("Do you like Rust?", options);
+    /// ```
+    pub fn id<S: Into<String>>(&mut self, id: S) -> Identified<'_> {
+        Identified { picker: self, session: None, id: id.into() }
+    }
+
+    /// Returns [`Picker::policy`], or the process-wide policy if not set.
+    fn effective_policy(&self) -> Policy {
+        self.policy.unwrap_or_else(policy)
+    }
+
+    /// Returns the default of yes-or-no overridden by [`Policy::AssumeYes`] and [`Policy::AssumeNo`].
+    fn assumed_yes(&self, default_yes: bool) -> bool {
+        match self.effective_policy() {
+            Policy::AssumeYes => true,
+            Policy::AssumeNo => false,
+            _ => default_yes,
+        }
+    }
+}
+
+fn yes_or_no_options(default_yes: bool) -> Result<Options> {
+    OptionsBuilder::default()
+        .item(Item::new_full("Yes", "y", 'y', None))
+        .item(Item::new_full("No", "n", 'n', None))
+        .current(if default_yes { 0 } else { 1 })
+        .build()
+}
+
+/// Identified struct is the [Picker] (or the [Session]) with the stable id of the next prompt,
+/// created by [`Picker::id`] or [`Session::id`].
+/// The methods are the same as the ones of [Picker], and the preseeded answer for the id is returned if given.
+pub struct Identified<'a> {
+    picker: &'a mut Picker,
+    /// The screen of the session, if created by [`Session::id`].
+    session: Option<&'a mut routine::SessionScreen>,
+    id: String,
+}
+
+impl Identified<'_> {
+    /// Same as [`Picker::choose`] with the preseeded answer.
+    pub fn choose(&mut self, prompt: &str, options: Options) -> Result<Option<String>> {
+        self.choose_outcome(prompt, options).map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::choose_outcome`] with the preseeded answer.
+    pub fn choose_outcome(&mut self, prompt: &str, options: Options) -> Result<Outcome<String>> {
+        log::info!("Picker choosing with prompt: {prompt} (id: {})", self.id);
+        match self.session.as_deref_mut() {
+            Some(session) => routine::choose_in_session(self.picker, session, prompt, options, Some(&self.id)),
+            None => routine::choose(self.picker, prompt, options, Some(&self.id)),
+        }
+    }
+
+    /// Same as [`Picker::yes_or_no`] with the preseeded answer.
+    pub fn yes_or_no(&mut self, prompt: &str, default_yes: bool) -> Result<Option<bool>> {
+        self.yes_or_no_outcome(prompt, default_yes).map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::yes_or_no_outcome`] with the preseeded answer.
+    pub fn yes_or_no_outcome(&mut self, prompt: &str, default_yes: bool) -> Result<Outcome<bool>> {
+        let options = yes_or_no_options(self.picker.assumed_yes(default_yes))?;
+        let answer = self.choose_outcome(prompt, options)?;
+        Ok(answer.map(|choice| choice == "Yes"))
+    }
+
+    /// Same as [`Picker::input`] with the preseeded answer.
+    pub fn input(&mut self, prompt: &str, initial: &str) -> Result<Option<String>> {
+        self.input_outcome(prompt, initial).map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::input_outcome`] with the preseeded answer. Any preseeded text is accepted.
+    pub fn input_outcome(&mut self, prompt: &str, initial: &str) -> Result<Outcome<String>> {
+        log::info!("Picker input with prompt: {prompt} (id: {})", self.id);
+        match self.session.as_deref_mut() {
+            Some(session) => routine::input_in_session(self.picker, session, prompt, initial, Some(&self.id)),
+            None => routine::input(self.picker, prompt, initial, Some(&self.id)),
+        }
+    }
+
+    /// Returns `true` if the prompt is answered without being shown, by the preseeded answer or the [Policy].
+    /// [Form] asks the text again when it cannot be parsed, but fails with [`Error::InvalidAnswer`] for such answers.
+    pub fn is_answered(&self) -> Result<bool> {
+        Ok(preseed::lookup(self.picker, &self.id)?.is_some() || self.picker.effective_policy() != Policy::Interactive)
+    }
+}
+
+/// Session runs many prompts in one terminal session, created by [`Picker::session`].
+/// The raw mode (and the alternate screen with [`Picker::alternate_screen`]) is entered by the first prompt shown,
+/// kept across the prompts without flickering, and restored once when the session is closed or dropped.
+///
+/// The previous answers stay visible: the prompts are stacked in the normal screen, and the summary lines
+/// ([`Picker::summary`], or the default one if not set) of the answered prompts are shown above the current prompt
+/// in the alternate screen. With [`Picker::summary`], the summary lines are printed after leaving the alternate screen.
+/// The prompts with ids ([`Session::id`]) are answered by the preseeded answers, and [`Form::prompt_in`] fills a struct
+/// in the session.
+///
+/// ```rust,no_run
+/// let mut picker = pick_a_boo::PickerBuilder::default()
+///     .summary(pick_a_boo::Summary::default())
+///     .build().expect("Failed to build Picker");
+/// let mut session = picker.session();
+/// let name = session.input("Project name?", "")?;
+/// let license = session.choose("License?", pick_a_boo::Options::from(&["MIT", "Apache-2.0"])?)?;
+/// let publish = session.yes_or_no("Publish?", false)?;
+/// session.close()?;
+/// # Ok::<(), pick_a_boo::Error>(())
+/// ```
+pub struct Session<'a> {
+    picker: &'a mut Picker,
+    screen: routine::SessionScreen,
+}
+
+impl Session<'_> {
+    /// Same as [`Picker::choose`] in this session.
+    pub fn choose(&mut self, prompt: &str, options: Options) -> Result<Option<String>> {
+        self.choose_outcome(prompt, options).map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::choose_outcome`] in this session.
+    pub fn choose_outcome(&mut self, prompt: &str, options: Options) -> Result<Outcome<String>> {
+        log::info!("Session choosing with prompt: {prompt}");
+        routine::choose_in_session(self.picker, &mut self.screen, prompt, options, None)
+    }
+
+    /// Same as [`Picker::yes_or_no`] in this session.
+    pub fn yes_or_no(&mut self, prompt: &str, default_yes: bool) -> Result<Option<bool>> {
+        self.yes_or_no_outcome(prompt, default_yes).map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::yes_or_no_outcome`] in this session.
+    pub fn yes_or_no_outcome(&mut self, prompt: &str, default_yes: bool) -> Result<Outcome<bool>> {
+        let options = yes_or_no_options(self.picker.assumed_yes(default_yes))?;
+        let answer = self.choose_outcome(prompt, options)?;
+        Ok(answer.map(|choice| choice == "Yes"))
+    }
+
+    /// Same as [`Picker::input`] in this session.
+    pub fn input(&mut self, prompt: &str, initial: &str) -> Result<Option<String>> {
+        self.input_outcome(prompt, initial).map(Outcome::selected)
+    }
+
+    /// Same as [`Picker::input_outcome`] in this session.
+    pub fn input_outcome(&mut self, prompt: &str, initial: &str) -> Result<Outcome<String>> {
+        log::info!("Session input with prompt: {prompt}");
+        routine::input_in_session(self.picker, &mut self.screen, prompt, initial, None)
+    }
+
+    /// Give the stable id to the next prompt in this session, for answering it with the preseeded answer.
+    /// See [`Picker::id`] for details.
+    pub fn id<S: Into<String>>(&mut self, id: S) -> Identified<'_> {
+        Identified { picker: self.picker, session: Some(&mut self.screen), id: id.into() }
+    }
+
+    /// Restore the terminal and finish the session.
+    /// Dropping the session does the same, but the errors are only logged.
+    pub fn close(mut self) -> Result<()> {
+        self.screen.close(self.picker)
+    }
+}
+
+impl Drop for Session<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.screen.close(self.picker) {
+            log::warn!("Failed to close the session: {e}");
+        }
+    }
+}
+
+/// StyledLine is a line of the picker rendered by [`PickerState::render`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StyledLine {
+    /// Text of the line, fitting in the given width.
+    pub text: String,
+    /// Whether the line should be drawn in reverse video, for [`InvalidKeyFeedback::Flash`].
+    pub reversed: bool,
+}
+
+/// PickerState is the state machine of choosing an option, for the applications with their own event loop
+/// (e.g., game-style TUIs). The application owns stdin and the raw mode, passes the terminal events to
+/// [`PickerState::handle_event`], and draws the lines from [`PickerState::render`],
+/// or lets [`PickerState::draw`] write them into the terminal.
+/// [`Picker::alternate_screen`], [`Picker::ephemeral`], and [`Picker::summary`] are left to the application,
+/// [`InvalidKeyFeedback::Bell`] is ignored by [`PickerState::render`], and Ctrl+Z does nothing, since they need the terminal.
+///
+/// ```rust
+/// use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+/// use pick_a_boo::{Options, Outcome, Picker, PickerState};
+/// let picker = Picker::default();
+/// let mut state = PickerState::new(&picker, "Do you like Rust?", Options::from(&["Yes", "No"]).unwrap());
+/// assert_eq!(state.render(80, 24)[0].text, "Do you like Rust?  Yes /n");
+/// let right = Event::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE));
+/// assert_eq!(state.handle_event(&right), None);
+/// let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+/// assert_eq!(state.handle_event(&enter), Some(Outcome::Selected("No".to_string())));
+/// ```
+pub struct PickerState<'a> {
+    choice: routine::Choice<'a>,
+    /// The frames written by [`PickerState::draw`].
+    canvas: screen::Canvas,
+}
+
+impl<'a> PickerState<'a> {
+    /// Create a PickerState instance choosing an option from the provided [Options] with the given prompt.
+    /// The countdown of [`Picker::timeout`] starts here.
+    pub fn new(picker: &'a Picker, prompt: &'a str, options: Options) -> Self {
+        PickerState { choice: routine::Choice::new(picker, prompt, options), canvas: screen::Canvas::new(false, false) }
+    }
+
+    /// Returns the options with the current item.
+    pub fn options(&self) -> &Options {
+        &self.choice.opts
+    }
+
+    /// Update the state by the given event, and returns the outcome if the prompt is finished.
+    /// Only the key events are handled, and the others are ignored.
+    pub fn handle_event(&mut self, event: &crossterm::event::Event) -> Option<Outcome<String>> {
+        match event {
+            crossterm::event::Event::Key(key) => self.handle_key(*key),
+            _ => None,
         }
     }
+
+    /// Update the state by the given key event, with the same key bindings as [`Picker::choose`].
+    /// Returns the outcome if the prompt is finished.
+    /// The release events are ignored, for the terminals reporting them (e.g., Windows).
+    pub fn handle_key(&mut self, key: crossterm::event::KeyEvent) -> Option<Outcome<String>> {
+        use routine::Widget;
+        if key.kind == crossterm::event::KeyEventKind::Release {
+            return None;
+        }
+        finished(self.choice.handle(key))
+    }
+
+    /// Returns the time when [`PickerState::tick`] should be called, for the countdown of [`Picker::timeout`]
+    /// and the transient feedback of the invalid keys. `None` if no update is scheduled.
+    pub fn deadline(&self) -> Option<std::time::Instant> {
+        use routine::Widget;
+        self.choice.deadline()
+    }
+
+    /// Update the time-dependent state, and returns the outcome if the countdown has expired.
+    pub fn tick(&mut self) -> Option<Outcome<String>> {
+        use routine::Widget;
+        finished(self.choice.tick())
+    }
+
+    /// Render the picker into the lines fitting in the given width.
+    /// The descriptions and the preview are scrolled to fit in the given height.
+    pub fn render(&self, width: usize, height: usize) -> Vec<StyledLine> {
+        use routine::Widget;
+        let frame = self.choice.frame(width, height);
+        frame.lines.into_iter()
+            .map(|text| StyledLine { text, reversed: frame.flash })
+            .collect()
+    }
+
+    /// Draw the picker below the cursor position of the terminal in the raw mode, e.g., stdout.
+    /// The first call reserves the lines, and the later calls write only the changed cells of them
+    /// in a single synchronized update.
+    pub fn draw<W: std::io::Write>(&mut self, out: &mut W, width: usize, height: usize) -> std::io::Result<()> {
+        use routine::Widget;
+        self.canvas.draw(out, &self.choice.frame(width, height))
+    }
+}
+
+fn finished<T>(step: routine::Step<T>) -> Option<Outcome<T>> {
+    match step {
+        routine::Step::Done(outcome) => Some(outcome),
+        routine::Step::Continue | routine::Step::Suspend => None,
+    }
+}
+
+/// PickerWidget renders [PickerState] into an area of the ratatui buffer (requires the `ratatui` feature),
+/// with the same look as the prompts of [Picker], that is, the delimiter, the parentheses, the layouts, and the descriptions.
+/// The lines exceeding the area are clipped.
+///
+/// ```rust,ignore
+/// let picker = pick_a_boo::Picker::default();
+/// let mut state = pick_a_boo::PickerState::new(&picker, "Deploy now?", options);
+/// terminal.draw(|frame| frame.render_stateful_widget(pick_a_boo::PickerWidget::new(), area, &mut state))?;
+/// if let crossterm::event::Event::Key(key) = crossterm::event::read()? {
+///     if let Some(outcome) = state.handle_key(key) { /* the prompt is finished */ }
+/// }
+/// ```
+#[cfg(feature = "ratatui")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PickerWidget<'a> {
+    style: ratatui_core::style::Style,
+    state: std::marker::PhantomData<PickerState<'a>>,
+}
+
+#[cfg(feature = "ratatui")]
+impl PickerWidget<'_> {
+    /// Create a PickerWidget instance with the default style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the style of the lines. The lines are reversed for [`InvalidKeyFeedback::Flash`].
+    pub fn style<S: Into<ratatui_core::style::Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+#[cfg(feature = "ratatui")]
+impl<'a> ratatui_core::widgets::StatefulWidget for PickerWidget<'a> {
+    type State = PickerState<'a>;
+
+    fn render(self, area: ratatui_core::layout::Rect, buf: &mut ratatui_core::buffer::Buffer, state: &mut PickerState<'a>) {
+        let lines = state.render(area.width as usize, area.height as usize);
+        for (row, line) in area.rows().zip(lines) {
+            let style = if line.reversed {
+                self.style.add_modifier(ratatui_core::style::Modifier::REVERSED)
+            } else {
+                self.style
+            };
+            buf.set_stringn(row.x, row.y, &line.text, row.width as usize, style);
+        }
+    }
+}
+
+/// Form trait fills the fields of a struct by asking them one by one with a [Picker].
+/// Use `#[derive(Form)]` to implement this trait, and annotate the fields to ask with `#[prompt(...)]`.
+///
+/// - `bool` fields are asked with [Picker::yes_or_no].
+/// - fields with `options = [...]` are asked with [Picker::choose], and the chosen label is converted with [std::str::FromStr].
+///   The labels are taken as they are, and each of them gets a distinct key by [Item::from_labels].
+/// - the other fields are asked with [Picker::input], and the entered text is converted with [std::str::FromStr].
+///   Text which cannot be converted is asked again, starting from the rejected text.
+///   When the text is given without asking (see [`Identified::is_answered`]), it fails with [`Error::InvalidAnswer`] instead.
+///
+/// The current values of the fields are used as the defaults, that is, the default answer of yes-or-no,
+/// the preselected option ([`Options`]' current), and the initial text of the input.
+/// Fields without `#[prompt(...)]` are left as they are.
+/// The field names are used as the prompt ids ([`Picker::id`]), so the fields can be answered by the preseeded answers,
+/// e.g., `PICK_A_BOO_ANSWER_LICENSE=MIT`.
+/// [`Form::prompt_in`] asks the fields in a [Session], e.g., to keep the answers visible with the other prompts.
+///
+/// ### Example
+///
+/// ```rust,no_run
+/// use pick_a_boo::{Form, Picker};
+///
+/// #[derive(Debug, Default, Form)]
+/// struct Config {
+///     #[prompt("Project name")]
+///     name: String,
+///     #[prompt("License", options = ["MIT", "Apache-2.0", "GPL-3.0"])]
+///     license: String,
+///     #[prompt("Number of workers")]
+///     workers: u32,
+///     #[prompt("Publish to crates.io?")]
+///     publish: bool,
+/// }
+///
+/// let mut picker = Picker::default();
+/// match Config::prompt(&mut picker) {
+///     Ok(Some(config)) => println!("{config:?}"),
+///     Ok(None) => println!("Cancelled."),
+///     Err(e) => eprintln!("Error: {e}"),
+/// }
+/// ```
+pub trait Form: Sized {
+    /// Ask the annotated fields in declaration order, starting from the values of `self`.
+    /// Returns `Ok(None)` if any of the questions is cancelled.
+    fn prompt_with(self, picker: &mut Picker) -> Result<Option<Self>>;
+
+    /// Same as [`Form::prompt_with`], but asks the fields in the given [Session].
+    fn prompt_in(self, session: &mut Session<'_>) -> Result<Option<Self>>;
+
+    /// Ask the annotated fields in declaration order, starting from the values of [Default].
+    /// Returns `Ok(None)` if any of the questions is cancelled.
+    fn prompt(picker: &mut Picker) -> Result<Option<Self>>
+    where
+        Self: Default,
+    {
+        Self::default().prompt_with(picker)
+    }
 }
 
 /// Helper function to ask a yes-or-no question with the given prompt.
@@ -586,12 +2073,12 @@ impl Picker {
 /// calling its [Picker::yes_or_no] method.
 /// 
 /// ```rust
-/// fn run_yes_or_no(prompt: &str, default_yes: bool) -> std::io::Result<Option<bool>> {
+/// fn run_yes_or_no(prompt: &str, default_yes: bool) -> pick_a_boo::Result<Option<bool>> {
 ///     pick_a_boo::Picker::default()
 ///         .yes_or_no(prompt, default_yes)
 /// }
 /// ```
-pub fn yes_or_no(prompt: &str, default_yes: bool) -> std::io::Result<Option<bool>> {
+pub fn yes_or_no(prompt: &str, default_yes: bool) -> Result<Option<bool>> {
     Picker::default()
         .yes_or_no(prompt, default_yes)
 }
@@ -601,12 +2088,12 @@ pub fn yes_or_no(prompt: &str, default_yes: bool) -> std::io::Result<Option<bool
 /// calling its [Picker::choose] method.
 /// 
 /// ```rust
-/// fn run_pick_a_boo(prompt: &str, options: pick_a_boo::Options) -> std::io::Result<Option<String>> {
+/// fn run_pick_a_boo(prompt: &str, options: pick_a_boo::Options) -> pick_a_boo::Result<Option<String>> {
 ///     pick_a_boo::Picker::default()
 ///         .choose(prompt, options)
 /// }
 /// ```
-pub fn choose(prompt: &str, options: Options) -> std::io::Result<Option<String>> {
+pub fn choose(prompt: &str, options: Options) -> Result<Option<String>> {
     Picker::default()
         .choose(prompt, options)
 }
@@ -621,7 +2108,7 @@ mod tests {
             .item(item!("Option 1", "o", "description 1"))
             .item(item!("Option 2", "o", "description 2")) // duplicate key
             .build();
-        assert!(result.is_err());
+        assert!(matches!(result, Err(crate::Error::DuplicateKey { key: 'o', first: 0, second: 1 })));
     }
 
     #[test]
@@ -631,7 +2118,7 @@ mod tests {
             .item(item!("Option 2", "2"))
             .current(10) // out of bounds
             .build();
-        assert!(result.is_err());
+        assert!(matches!(result, Err(crate::Error::CurrentOutOfBounds { index: 10, len: 2 })));
     }
 
     #[test]
@@ -645,7 +2132,372 @@ mod tests {
     fn test_optionsbuilder_no_items() {
         let result = crate::OptionsBuilder::default()
             .build();
-        assert!(result.is_err());
+        assert!(matches!(result, Err(crate::Error::EmptyItems)));
+    }
+
+    #[test]
+    fn test_options_from_empty_slice() {
+        let result = crate::Options::from::<&str>(&[]);
+        assert!(matches!(result, Err(crate::Error::EmptyItems)));
+    }
+
+    #[test]
+    fn test_error_into_io_error() {
+        let e: std::io::Error = crate::Error::Io(std::io::Error::from(std::io::ErrorKind::Interrupted)).into();
+        assert_eq!(e.kind(), std::io::ErrorKind::Interrupted);
+        let e: std::io::Error = crate::Error::NotATty.into();
+        assert_eq!(e.kind(), std::io::ErrorKind::Other);
+        assert_eq!(e.to_string(), crate::Error::NotATty.to_string());
+    }
+
+    fn display_options() -> crate::Options {
+        crate::OptionsBuilder::default()
+            .item(item!("Yes", "y", "I love it"))
+            .item(item!("So so", short = "So", description = "I like it, but sometimes it's hard"))
+            .item(item!("Maybe", key = 'm', description = "I haven't tried it yet"))
+            .item(item!("No", "n", "I don't like it"))
+            .current(2)
+            .build().unwrap()
+    }
+
+    #[test]
+    fn test_display_unselected_items() {
+        use crate::{PickerBuilder, UnselectedDisplay};
+        let opts = display_options();
+        let expected = [
+            (UnselectedDisplay::Key, "y/S/ Maybe /n"),
+            (UnselectedDisplay::ShortLabel, "y/So/ Maybe /n"),
+            (UnselectedDisplay::LongLabel, "Yes/So so/ Maybe /No"),
+            (UnselectedDisplay::Hidden, " Maybe "),
+        ];
+        for (mode, want) in expected {
+            let picker = PickerBuilder::default().unselected_display(mode).build().unwrap();
+            assert_eq!(opts.display(&picker).to_string(), want);
+        }
+    }
+
+    #[test]
+    fn test_display_selected_decoration() {
+        let picker = crate::PickerBuilder::default()
+            .selected_decoration("[", "]")
+            .build().unwrap();
+        assert_eq!(display_options().display(&picker).to_string(), "y/S/[Maybe]/n");
+    }
+
+    #[test]
+    fn test_pickerbuilder_invalid_template() {
+        let result = crate::PickerBuilder::default()
+            .template("{prompt} {choices}")
+            .build();
+        assert!(matches!(result, Err(crate::Error::InvalidTemplate { reason, .. }) if reason == "unknown placeholder {choices}"));
+    }
+
+    #[test]
+    fn test_policy_assume_default() {
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeDefault)
+            .build().unwrap();
+        let options = crate::OptionsBuilder::default()
+            .item(item!("Yes", "y"))
+            .item(item!("No", "n"))
+            .current(1)
+            .build().unwrap();
+        assert_eq!(picker.choose("Continue?", options).unwrap(), Some("No".to_string()));
+        assert_eq!(picker.yes_or_no("Continue?", true).unwrap(), Some(true));
+        assert_eq!(picker.input("Name?", "pick-a-boo").unwrap(), Some("pick-a-boo".to_string()));
+    }
+
+    #[test]
+    fn test_policy_assume_yes_and_no() {
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeYes)
+            .build().unwrap();
+        assert_eq!(picker.yes_or_no("Overwrite?", false).unwrap(), Some(true));
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeNo)
+            .build().unwrap();
+        assert_eq!(picker.id("overwrite").yes_or_no("Overwrite?", true).unwrap(), Some(false));
+    }
+
+    #[test]
+    fn test_policy_fail_if_interactive() {
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::FailIfInteractive)
+            .build().unwrap();
+        let result = picker.yes_or_no("Overwrite?", false);
+        assert!(matches!(result, Err(crate::Error::InteractionRequired { prompt }) if prompt == "Overwrite?"));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_choose_async_with_policy() {
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeYes)
+            .build().unwrap();
+        let options = crate::Options::from(&["Yes", "No"]).unwrap();
+        assert_eq!(picker.choose_async("Continue?", options).await.unwrap(), Some("Yes".to_string()));
+        assert_eq!(picker.yes_or_no_async("Overwrite?", false).await.unwrap(), Some(true));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_choose_async_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+        let mut picker = crate::Picker::default();
+        let options = crate::Options::from(&["Yes", "No"]).unwrap();
+        assert_send(picker.choose_async("Continue?", options));
+    }
+
+    #[test]
+    fn test_picker_state_with_own_event_loop() {
+        use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+        let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        let picker = crate::PickerBuilder::default()
+            .invalid_key_feedback(crate::InvalidKeyFeedback::Flash)
+            .build().unwrap();
+        let options = crate::Options::from(&["Yes", "So so", "No"]).unwrap();
+        let mut state = crate::PickerState::new(&picker, "Do you like Rust?", options);
+        assert_eq!(state.handle_event(&key(KeyCode::Char('s'))), None);
+        assert_eq!(state.options().current, 1);
+        assert_eq!(state.handle_event(&Event::Resize(10, 10)), None);
+        assert_eq!(state.handle_event(&key(KeyCode::Char('x'))), None);
+        assert!(state.deadline().is_some());
+        let lines = state.render(80, 24);
+        assert_eq!(lines, vec![crate::StyledLine { text: "Do you like Rust? y/ So so /n".to_string(), reversed: true }]);
+        assert_eq!(state.handle_event(&key(KeyCode::Esc)), Some(crate::Outcome::Cancelled(crate::CancelReason::Escape)));
+    }
+
+    #[test]
+    fn test_picker_state_draw() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        let picker = crate::Picker::default();
+        let mut state = crate::PickerState::new(&picker, "Do you like Rust?", crate::Options::from(&["Yes", "No"]).unwrap());
+        let mut out = Vec::new();
+        state.draw(&mut out, 80, 24).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("Do you like Rust?  Yes /n"));
+        state.handle_key(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE));
+        let mut out = Vec::new();
+        state.draw(&mut out, 80, 24).unwrap();
+        // only the changed part is drawn, inside a synchronized update
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\x1b[?2026h") && out.ends_with("\x1b[?2026l"));
+        assert!(out.contains("y/ No ") && !out.contains("Do you like Rust?"));
+    }
+
+    #[cfg(feature = "ratatui")]
+    #[test]
+    fn test_picker_widget() {
+        use ratatui_core::{buffer::Buffer, layout::Rect, widgets::StatefulWidget};
+        let picker = crate::PickerBuilder::default()
+            .paren("[]")
+            .description_show_mode(crate::DescriptionShowMode::All)
+            .build().unwrap();
+        let options = crate::OptionsBuilder::default()
+            .item(item!("Yes", "y", "I love it"))
+            .item(item!("No", "n", "I don't like it"))
+            .build().unwrap();
+        let mut state = crate::PickerState::new(&picker, "Rust?", options);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 2));
+        crate::PickerWidget::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Rust? [ Yes /n]     ", "> Yes I love it     "]));
+    }
+
+    #[test]
+    fn test_derive_form_with_labels_sharing_first_letter() {
+        use crate::Form;
+        #[derive(Debug, Form)]
+        struct Config {
+            #[prompt("License", options = ["MIT", "MPL-2.0", "CC0: public domain"])]
+            license: String,
+        }
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeDefault)
+            .build().unwrap();
+        let config = Config { license: "MPL-2.0".to_string() }.prompt_with(&mut picker).unwrap().unwrap();
+        assert_eq!(config.license, "MPL-2.0");
+        let config = Config { license: "CC0: public domain".to_string() }.prompt_with(&mut picker).unwrap().unwrap();
+        assert_eq!(config.license, "CC0: public domain");
+    }
+
+    #[test]
+    fn test_derive_form_rejects_invalid_answer_without_asking() {
+        use crate::Form;
+        /// The port shown as empty until it is set.
+        #[derive(Debug, Default)]
+        struct Port(Option<u16>);
+        impl std::fmt::Display for Port {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self.0 {
+                    Some(port) => write!(f, "{port}"),
+                    None => Ok(()),
+                }
+            }
+        }
+        impl std::str::FromStr for Port {
+            type Err = std::num::ParseIntError;
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                s.parse().map(|port| Port(Some(port)))
+            }
+        }
+        #[derive(Debug, Default, Form)]
+        struct Config {
+            #[prompt("Port")]
+            port: Port,
+        }
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeDefault)
+            .build().unwrap();
+        let config = Config { port: Port(Some(8080)) }.prompt_with(&mut picker).unwrap().unwrap();
+        assert_eq!(config.port.0, Some(8080));
+        match Config::default().prompt_with(&mut picker) {
+            Err(crate::Error::InvalidAnswer { prompt, answer, .. }) => assert_eq!((prompt.as_str(), answer.as_str()), ("Port", "")),
+            result => panic!("unexpected result: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_session_with_policy() {
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeNo)
+            .build().unwrap();
+        let mut session = picker.session();
+        assert_eq!(session.input("Name?", "pick-a-boo").unwrap(), Some("pick-a-boo".to_string()));
+        assert_eq!(session.yes_or_no("Publish?", true).unwrap(), Some(false));
+        session.close().unwrap();
+    }
+
+    #[cfg(feature = "preseed-file")]
+    #[test]
+    fn test_session_with_preseeded_answers() {
+        use crate::Form;
+        #[derive(Debug, Default, Form)]
+        struct Config {
+            #[prompt("License", options = ["MIT", "MPL-2.0"])]
+            license: String,
+            #[prompt("Publish?")]
+            publish: bool,
+        }
+        let path = std::env::temp_dir().join(format!("pick-a-boo-session-{}.toml", std::process::id()));
+        std::fs::write(&path, "license = \"MPL-2.0\"\npublish = true\nlicense-of-docs = \"CC0\"\n").unwrap();
+        let mut picker = crate::PickerBuilder::default()
+            .answers_file(&path)
+            .policy(crate::Policy::FailIfInteractive)
+            .build().unwrap();
+        let mut session = picker.session();
+        let docs = session.id("license-of-docs").choose("License of docs", crate::Options::from(&["BSD", "CC0"]).unwrap());
+        let config = Config::default().prompt_in(&mut session);
+        session.close().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(docs.unwrap(), Some("CC0".to_string()));
+        let config = config.unwrap().unwrap();
+        assert_eq!((config.license.as_str(), config.publish), ("MPL-2.0", true));
+    }
+
+    #[test]
+    fn test_form_in_session() {
+        use crate::Form;
+        #[derive(Debug, Form)]
+        struct Config {
+            #[prompt("Project name")]
+            name: String,
+            #[prompt("Publish?")]
+            publish: bool,
+        }
+        let mut picker = crate::PickerBuilder::default()
+            .policy(crate::Policy::AssumeNo)
+            .build().unwrap();
+        let mut session = picker.session();
+        let config = Config { name: "pick-a-boo".to_string(), publish: true }.prompt_in(&mut session).unwrap().unwrap();
+        session.close().unwrap();
+        assert_eq!((config.name.as_str(), config.publish), ("pick-a-boo", false));
+    }
+
+    #[test]
+    fn test_printer_queues_lines_while_active() {
+        use std::io::Write;
+        let printer = crate::Printer::new();
+        let mut clone = printer.clone();
+        printer.set_active(true);
+        printer.println("downloaded 1/3");
+        write!(clone, "downloaded 2/3\ndownloaded").unwrap();
+        assert_eq!(printer.take_pending(), vec!["downloaded 1/3", "downloaded 2/3"]);
+        clone.write_all(b" 3/3\r\n").unwrap();
+        assert_eq!(printer.take_pending(), vec!["downloaded 3/3"]);
+        assert!(printer.take_pending().is_empty());
+        printer.set_active(false);
+    }
+
+    #[test]
+    fn test_printer_suspend_without_picker() {
+        assert_eq!(crate::Printer::new().suspend(|| 42), 42);
+    }
+
+    #[test]
+    fn test_printer_suspend_hands_over() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        let printer = crate::Printer::new();
+        printer.set_active(true);
+        let clone = printer.clone();
+        let done = std::sync::Arc::new(AtomicBool::new(false));
+        let flag = std::sync::Arc::clone(&done);
+        let handle = std::thread::spawn(move || clone.suspend(|| {
+            flag.store(true, Ordering::SeqCst);
+            7
+        }));
+        while !printer.suspend_requested() {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        // the closure waits until the picker hides itself
+        assert!(!done.load(Ordering::SeqCst));
+        printer.hand_over();
+        assert!(done.load(Ordering::SeqCst));
+        assert_eq!(handle.join().unwrap(), 7);
+        assert!(!printer.suspend_requested());
+        printer.set_active(false);
+    }
+
+    #[test]
+    fn test_printer_paused_between_prompts() {
+        let printer = crate::Printer::new();
+        printer.set_active(true);
+        printer.println("queued");
+        printer.pause(false, false);
+        assert!(printer.take_pending().is_empty());
+        printer.println("printed right away");
+        assert!(printer.take_pending().is_empty());
+        assert_eq!(printer.resume(), Some(true));
+        printer.println("queued again");
+        assert_eq!(printer.take_pending(), vec!["queued again"]);
+        assert_eq!(printer.resume(), None);
+        // the lines are kept until leaving the alternate screen
+        printer.pause(true, false);
+        printer.println("printed on leaving");
+        assert_eq!(printer.resume(), Some(false));
+        assert_eq!(printer.take_pending(), vec!["printed on leaving"]);
+        printer.set_active(false);
+    }
+
+    #[test]
+    fn test_cancel_token_shared_by_clones() {
+        let flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let token = crate::CancelToken::from(flag.clone());
+        let clone = token.clone();
+        assert!(!token.is_cancelled());
+        clone.cancel();
+        assert!(token.is_cancelled());
+        assert!(flag.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_outcome_selected() {
+        use crate::{CancelReason, Outcome};
+        assert_eq!(Outcome::Selected("Yes").selected(), Some("Yes"));
+        assert_eq!(Outcome::<&str>::Cancelled(CancelReason::Escape).selected(), None);
+        assert_eq!(Outcome::<&str>::Interrupted.selected(), None);
+        assert_eq!(Outcome::Selected("Yes").map(|s| s == "Yes"), Outcome::Selected(true));
+        assert_eq!(Outcome::<&str>::Eof.map(|s| s == "Yes"), Outcome::Eof);
+        assert_eq!(Outcome::<&str>::TimedOut.map(|s| s == "Yes"), Outcome::TimedOut);
+        assert_eq!(Outcome::<&str>::Aborted.map(|s| s == "Yes"), Outcome::Aborted);
     }
 
     #[test]
@@ -664,6 +2516,26 @@ mod tests {
         assert_eq!(it.description.as_deref(), Some("This is example"));
     }
 
+    #[test]
+    fn test_item_from_labels() {
+        let items = crate::Item::from_labels(&["MIT", "MPL-2.0", "M", "Time: now"]);
+        assert_eq!(items.iter().map(|item| item.key).collect::<String>(), "mp1t");
+        assert_eq!(items[3].long_label, "Time: now");
+        assert_eq!(items[3].description, None);
+    }
+
+    #[test]
+    fn test_item_from_labels_beyond_typable_keys() {
+        let labels = (0..40).map(|n| format!("#{n}")).collect::<Vec<_>>();
+        let items = crate::Item::from_labels(&labels);
+        let keys = items.iter().map(|item| item.key).collect::<std::collections::HashSet<_>>();
+        assert_eq!(keys.len(), 40);
+        assert_eq!(items[0].key, '0');
+        assert_eq!(items[36].key, '\u{e000}');
+        assert_eq!(items[36].short_label, "#36");
+        assert!(crate::OptionsBuilder::default().items(items).build().is_ok());
+    }
+
     #[test]
     fn test_macro_item_1() {
         let it = item!("Alpha");
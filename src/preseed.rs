@@ -0,0 +1,191 @@
+//! Preseeded answers for answering the prompts without a terminal, in the spirit of debconf preseeding.
+//! The answers are given by the environment variables, or by the answers file (`preseed-file` feature),
+//! keyed by the prompt ids ([Picker::id]).
+use crate::{Error, Options, Picker, Result};
+#[cfg(feature = "preseed-file")]
+use std::path::{Path, PathBuf};
+
+/// Prefix of the environment variables giving the answers.
+const ANSWER_ENV_PREFIX: &str = "PICK_A_BOO_ANSWER_";
+/// Environment variable giving the path of the answers file, if [Picker::answers_file] is not set.
+#[cfg(feature = "preseed-file")]
+const ANSWERS_FILE_ENV: &str = "PICK_A_BOO_ANSWERS_FILE";
+
+/// Returns the name of the environment variable for the given id, e.g., `PICK_A_BOO_ANSWER_RUST_FEELING` for `rust-feeling`.
+fn env_name(id: &str) -> String {
+    let id = id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect::<String>();
+    format!("{ANSWER_ENV_PREFIX}{id}")
+}
+
+/// Look up the preseeded answer for the given id from the environment variable, and then from the answers file.
+pub(crate) fn lookup(picker: &Picker, id: &str) -> Result<Option<String>> {
+    if let Ok(answer) = std::env::var(env_name(id)) {
+        return Ok(Some(answer));
+    }
+    lookup_file(picker, id)
+}
+
+#[cfg(not(feature = "preseed-file"))]
+fn lookup_file(_picker: &Picker, _id: &str) -> Result<Option<String>> {
+    Ok(None)
+}
+
+/// Look up the preseeded answer for the given id from the answers file, if any.
+#[cfg(feature = "preseed-file")]
+fn lookup_file(picker: &Picker, id: &str) -> Result<Option<String>> {
+    let path = picker.answers_file.clone()
+        .or_else(|| std::env::var_os(ANSWERS_FILE_ENV).map(PathBuf::from));
+    match path {
+        Some(path) => {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| Error::AnswersFile { path: path.clone(), reason: e.to_string() })?;
+            parse_answers(&path, &content, id)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Parse the answers file, and returns the answer for the given id.
+/// The file is parsed as JSON if the extension is `.json`, and as TOML otherwise.
+/// Booleans are converted into `Yes` and `No` for [Picker::yes_or_no].
+#[cfg(feature = "preseed-file")]
+fn parse_answers(path: &Path, content: &str, id: &str) -> Result<Option<String>> {
+    let error = |reason: String| Error::AnswersFile { path: path.to_path_buf(), reason };
+    if path.extension().is_some_and(|ext| ext == "json") {
+        let answers = serde_json::from_str::<serde_json::Value>(content).map_err(|e| error(e.to_string()))?;
+        let answers = answers.as_object().ok_or_else(|| error("not a JSON object".to_string()))?;
+        match answers.get(id) {
+            None => Ok(None),
+            Some(serde_json::Value::String(s)) => Ok(Some(s.clone())),
+            Some(serde_json::Value::Bool(b)) => Ok(Some(yes_or_no(*b))),
+            Some(serde_json::Value::Number(n)) => Ok(Some(n.to_string())),
+            Some(_) => Err(error(format!("{id}: the answer must be a string, a boolean, or a number"))),
+        }
+    } else {
+        let answers = content.parse::<toml::Table>().map_err(|e| error(e.to_string()))?;
+        match answers.get(id) {
+            None => Ok(None),
+            Some(toml::Value::String(s)) => Ok(Some(s.clone())),
+            Some(toml::Value::Boolean(b)) => Ok(Some(yes_or_no(*b))),
+            Some(toml::Value::Integer(i)) => Ok(Some(i.to_string())),
+            Some(toml::Value::Float(f)) => Ok(Some(f.to_string())),
+            Some(_) => Err(error(format!("{id}: the answer must be a string, a boolean, or a number"))),
+        }
+    }
+}
+
+#[cfg(feature = "preseed-file")]
+fn yes_or_no(b: bool) -> String {
+    if b { "Yes" } else { "No" }.to_string()
+}
+
+/// Resolve the preseeded answer into the long label of the matching item.
+/// The answer is matched with the long labels, the keys, and the short labels (ignoring case) in this order.
+pub(crate) fn resolve(opts: &Options, id: &str, answer: &str) -> Result<String> {
+    let mut chars = answer.chars();
+    let key = match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    };
+    opts.iter().find(|item| item.long_label == answer)
+        .or_else(|| opts.iter().find(|item| Some(item.key) == key))
+        .or_else(|| opts.iter().find(|item| item.long_label.eq_ignore_ascii_case(answer)))
+        .or_else(|| opts.iter().find(|item| item.short_label.eq_ignore_ascii_case(answer)))
+        .map(|item| item.long_label.clone())
+        .ok_or_else(|| Error::InvalidPreseed {
+            id: id.to_string(),
+            answer: answer.to_string(),
+            reason: format!(
+                "expected one of {}",
+                opts.iter().map(|item| item.long_label.as_str()).collect::<Vec<_>>().join(", ")
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    mod env_name {
+        use super::super::*;
+
+        #[test]
+        fn test_env_name() {
+            assert_eq!(env_name("rust-feeling"), "PICK_A_BOO_ANSWER_RUST_FEELING");
+            assert_eq!(env_name("license"), "PICK_A_BOO_ANSWER_LICENSE");
+        }
+    }
+
+    #[cfg(feature = "preseed-file")]
+    mod parse_answers {
+        use super::super::*;
+
+        #[test]
+        fn test_toml() {
+            let content = "rust-feeling = \"Maybe\"\npublish = true\nworkers = 4\n";
+            let path = Path::new("answers.toml");
+            assert_eq!(parse_answers(path, content, "rust-feeling").unwrap(), Some("Maybe".to_string()));
+            assert_eq!(parse_answers(path, content, "publish").unwrap(), Some("Yes".to_string()));
+            assert_eq!(parse_answers(path, content, "workers").unwrap(), Some("4".to_string()));
+            assert_eq!(parse_answers(path, content, "unknown").unwrap(), None);
+        }
+
+        #[test]
+        fn test_json() {
+            let content = r#"{"rust-feeling": "Maybe", "publish": false}"#;
+            let path = Path::new("answers.json");
+            assert_eq!(parse_answers(path, content, "rust-feeling").unwrap(), Some("Maybe".to_string()));
+            assert_eq!(parse_answers(path, content, "publish").unwrap(), Some("No".to_string()));
+        }
+
+        #[test]
+        fn test_invalid_files() {
+            let result = parse_answers(Path::new("answers.json"), "[1, 2]", "id");
+            assert!(matches!(result, Err(Error::AnswersFile { reason, .. }) if reason == "not a JSON object"));
+            let result = parse_answers(Path::new("answers.toml"), "id = [\"Maybe\"]", "id");
+            assert!(matches!(result, Err(Error::AnswersFile { .. })));
+        }
+    }
+
+    mod resolve {
+        use super::super::*;
+
+        fn options() -> Options {
+            Options::from(&["Yes", "So so", "Maybe", "No"]).unwrap()
+        }
+
+        #[test]
+        fn test_resolve_labels_and_keys() {
+            assert_eq!(resolve(&options(), "id", "Maybe").unwrap(), "Maybe");
+            assert_eq!(resolve(&options(), "id", "s").unwrap(), "So so");
+            assert_eq!(resolve(&options(), "id", "no").unwrap(), "No");
+        }
+
+        #[test]
+        fn test_resolve_invalid_answer() {
+            let result = resolve(&options(), "rust-feeling", "Never");
+            assert!(matches!(result, Err(Error::InvalidPreseed { id, answer, .. }) if id == "rust-feeling" && answer == "Never"));
+        }
+    }
+
+    #[cfg(feature = "preseed-file")]
+    mod lookup {
+        use super::super::*;
+
+        #[test]
+        fn test_lookup_from_answers_file() {
+            let path = std::env::temp_dir().join(format!("pick-a-boo-answers-{}.toml", std::process::id()));
+            std::fs::write(&path, "license = \"MIT\"\n").unwrap();
+            let picker = crate::PickerBuilder::default().answers_file(&path).build().unwrap();
+            let answer = lookup(&picker, "license");
+            std::fs::remove_file(&path).unwrap();
+            assert_eq!(answer.unwrap(), Some("MIT".to_string()));
+        }
+
+        #[test]
+        fn test_lookup_from_missing_file() {
+            let picker = crate::PickerBuilder::default().answers_file("/nonexistent/answers.toml").build().unwrap();
+            assert!(matches!(lookup(&picker, "license"), Err(Error::AnswersFile { .. })));
+        }
+    }
+}
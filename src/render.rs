@@ -0,0 +1,967 @@
+//! Rendering the picker into the lines of the terminal.
+//! The functions in this module are pure, that is, they only build the lines
+//! fitting in the given terminal size, and [crate::screen::Canvas] draws them.
+use crate::{DescriptionNameWidth, DescriptionShowMode, Footer, Layout, Options, Outcome, Picker, PreviewPosition, Summary};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Frame is the lines to draw, and the cursor position (row, column) if the cursor should be shown.
+/// `bell` rings the terminal bell on drawing, and `flash` draws the lines in reverse video.
+#[derive(Debug, Default)]
+pub(crate) struct Frame {
+    pub(crate) lines: Vec<String>,
+    pub(crate) cursor: Option<(usize, usize)>,
+    pub(crate) bell: bool,
+    pub(crate) flash: bool,
+}
+
+/// Build the frame of the choice, the prompt line and the descriptions.
+/// The lines are wrapped to `width`, and the descriptions are scrolled to fit in `height` with the current item.
+/// `default` is the index of the item which was current when the prompt started.
+pub(crate) fn choice(picker: &Picker, prompt: &str, opts: &Options, default: usize, width: usize, height: usize) -> Frame {
+    let layout = layout(picker, prompt, opts, default, width);
+    let mut lines = wrap(&prompt_line(picker, prompt, opts, default, layout == Layout::Inline), width);
+    let rest = height.saturating_sub(lines.len());
+    match layout {
+        Layout::List => lines.extend(list_rows(picker, opts, width, rest)),
+        Layout::Grid => {
+            let description = match picker.description_show_mode {
+                DescriptionShowMode::Never => vec![],
+                _ => current_description(opts, calculate_name_width(picker, opts), width),
+            };
+            lines.extend(grid_rows(opts, width, rest.saturating_sub(description.len())));
+            lines.extend(description);
+        }
+        _ => lines.extend(descriptions(picker, opts, width, rest)),
+    }
+    Frame { lines, ..Frame::default() }
+}
+
+/// Build the frame of the choice with the preview pane.
+/// `preview` is the preview lines and the index of the first line shown in the pane.
+/// The pane is placed below or beside the choice according to [crate::Preview::position].
+pub(crate) fn choice_with_preview(
+    picker: &Picker,
+    prompt: &str,
+    opts: &Options,
+    default: usize,
+    (preview, scroll): (&[String], usize),
+    width: usize,
+    height: usize,
+) -> Frame {
+    let Some(settings) = &picker.preview else {
+        return choice(picker, prompt, opts, default, width, height);
+    };
+    match settings.position {
+        PreviewPosition::Below => {
+            let rows = settings.height.min(preview.len()).min(height.saturating_sub(2));
+            let mut frame = choice(picker, prompt, opts, default, width, height.saturating_sub(rows + 1));
+            frame.lines.push("─".repeat(width));
+            frame.lines.extend(pane(preview, scroll, rows, width));
+            frame
+        }
+        PreviewPosition::Beside => {
+            let left_width = options_width(picker, width);
+            let right_width = width.saturating_sub(left_width + 3);
+            let left = choice(picker, prompt, opts, default, left_width, height).lines;
+            let rows = left.len().max(settings.height.min(preview.len())).min(height);
+            let right = pane(preview, scroll, rows, right_width);
+            let lines = (0..rows)
+                .map(|row| {
+                    let l = left.get(row).map(String::as_str).unwrap_or("");
+                    let r = right.get(row).map(String::as_str).unwrap_or("");
+                    format!("{} │ {r}", pad(l, left_width)).trim_end().to_string()
+                }).collect();
+            Frame { lines, ..Frame::default() }
+        }
+    }
+}
+
+/// Returns the width of the options pane in the terminal of the given width,
+/// that is, the left half if the preview pane is placed beside the options.
+pub(crate) fn options_width(picker: &Picker, width: usize) -> usize {
+    match &picker.preview {
+        Some(settings) if settings.position == PreviewPosition::Beside => width / 2,
+        _ => width,
+    }
+}
+
+/// Returns the `rows` lines of the preview pane from the `scroll`-th line, truncated to `width`.
+/// The scroll position is clamped so that the pane is filled as much as possible.
+fn pane(preview: &[String], scroll: usize, rows: usize, width: usize) -> Vec<String> {
+    let start = scroll.min(preview.len().saturating_sub(rows));
+    preview.iter().skip(start).take(rows)
+        .map(|line| truncate(line, width))
+        .collect()
+}
+
+/// Truncate the given string to the given display width.
+fn truncate(s: &str, width: usize) -> String {
+    let mut current_width = 0;
+    s.chars()
+        .take_while(|c| {
+            current_width += c.width().unwrap_or(0);
+            current_width <= width
+        }).collect()
+}
+
+/// Build the footer line listing the available keys, if [Picker::footer] is set.
+/// The keys moving the current item depend on the layout, e.g., `←/→` for [Layout::Inline].
+pub(crate) fn footer(picker: &Picker, prompt: &str, opts: &Options, default: usize, width: usize) -> Vec<String> {
+    let Some(footer) = &picker.footer else {
+        return vec![];
+    };
+    if let Some(text) = &footer.text {
+        return wrap(text, width);
+    }
+    let moves = match layout(picker, prompt, opts, default, options_width(picker, width)) {
+        Layout::List => "↑/↓",
+        Layout::Grid => "←/→/↑/↓",
+        _ => "←/→",
+    };
+    let keys = opts.iter().map(|item| item.key.to_string()).collect::<Vec<_>>().join("/");
+    let mut hints = vec![
+        format!("{moves} {}", footer.move_label),
+        format!("enter {}", footer.select_label),
+        format!("esc {}", footer.cancel_label),
+        format!("{keys} {}", footer.jump_label),
+    ];
+    if picker.preview.is_some() {
+        hints.push(format!("shift+↑/↓ {}", footer.scroll_label));
+    }
+    if opts.iter().all(|item| item.key != '?') {
+        hints.push(format!("? {}", footer.help_label));
+    }
+    wrap(&hints.join(&footer.separator), width)
+}
+
+/// Build the countdown line of [Picker::timeout] with the remaining seconds.
+pub(crate) fn countdown(picker: &Picker, opts: &Options, seconds: u64, width: usize) -> Vec<String> {
+    let Some(timeout) = &picker.timeout else {
+        return vec![];
+    };
+    let line = timeout.message
+        .replace("{answer}", &opts.current_name())
+        .replace("{seconds}", &seconds.to_string());
+    wrap(&line, width)
+}
+
+/// Build the frame of the help overlay, listing the key bindings, the description of the current item,
+/// and the item keys with their labels, followed by the hint for returning (and scrolling if the item keys overflow).
+/// The labels of the bindings shared with the footer are taken from [Picker::footer] (or the default [Footer]).
+/// The item keys are scrolled from the `scroll`-th row (clamped) to fit in `height`.
+/// Returns the frame, and the number of the rows of the item keys shown and the maximum scroll, for scrolling by keys.
+pub(crate) fn help(picker: &Picker, prompt: &str, opts: &Options, scroll: usize, width: usize, height: usize) -> (Frame, HelpPage) {
+    let default_footer = Footer::default();
+    let footer = picker.footer.as_ref().unwrap_or(&default_footer);
+    let mut bindings = vec![
+        ("←/→ ↑/↓".to_string(), footer.move_label.clone()),
+        ("enter".to_string(), footer.select_label.clone()),
+        ("esc".to_string(), footer.cancel_label.clone()),
+        ("ctrl+c".to_string(), "interrupt".to_string()),
+        ("ctrl+d".to_string(), "end of input".to_string()),
+        ("ctrl+z".to_string(), "suspend".to_string()),
+    ];
+    if picker.preview.is_some() {
+        bindings.push(("shift+↑/↓ pgup/pgdn".to_string(), footer.scroll_label.clone()));
+    }
+    bindings.push(("?".to_string(), footer.help_label.clone()));
+    let keys = opts.iter().map(|item| (item.key.to_string(), item.long_label.clone())).collect::<Vec<_>>();
+    let key_width = bindings.iter().chain(&keys).map(|(key, _)| key.width()).max().unwrap_or(0);
+    let rows = |bindings: &[(String, String)]| bindings.iter()
+        .flat_map(|(key, label)| wrap_hanging(&format!("  {}  ", pad(key, key_width)), label, width))
+        .collect::<Vec<_>>();
+    let mut lines = wrap(&format!("{prompt} (help)"), width);
+    lines.extend(rows(&bindings));
+    let item = opts.current_item();
+    match &item.description {
+        Some(description) => lines.extend(wrap_hanging(&format!("{}: ", item.long_label), description, width)),
+        None => lines.extend(wrap(&item.long_label, width)),
+    }
+    let key_rows = rows(&keys);
+    let mut hint = wrap("press any key to return", width);
+    if lines.len() + key_rows.len() + hint.len() > height {
+        hint = wrap(&format!("↑/↓ pgup/pgdn {} · any other key to return", footer.scroll_label), width);
+    }
+    let shown = height.saturating_sub(lines.len() + hint.len()).min(key_rows.len());
+    let max = key_rows.len() - shown;
+    let scroll = scroll.min(max);
+    lines.extend(key_rows.into_iter().skip(scroll).take(shown));
+    lines.extend(hint);
+    lines.truncate(height.max(1));
+    (Frame { lines, ..Frame::default() }, HelpPage { rows: shown, max })
+}
+
+/// HelpPage is the item keys shown in the help overlay, for scrolling them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct HelpPage {
+    /// The number of the rows of the item keys shown.
+    pub(crate) rows: usize,
+    /// The maximum first row, at which the last row is shown.
+    pub(crate) max: usize,
+}
+
+/// The maximum number of items shown inline by [Layout::Auto].
+const AUTO_INLINE_MAX_ITEMS: usize = 5;
+
+/// Resolve [Picker::layout] into the concrete layout for the given terminal width.
+/// [Layout::Auto] is resolved into [Layout::Inline] if the items are few and the prompt line fits in a row,
+/// into [Layout::Grid] if no descriptions are shown and two or more columns fit in the width,
+/// and otherwise into [Layout::List].
+pub(crate) fn layout(picker: &Picker, prompt: &str, opts: &Options, default: usize, width: usize) -> Layout {
+    if picker.layout != Layout::Auto {
+        return picker.layout.clone();
+    }
+    let inline = prompt_line(picker, prompt, opts, default, true);
+    if opts.items.len() <= AUTO_INLINE_MAX_ITEMS && inline.split('\n').all(|line| line.width() <= width) {
+        return Layout::Inline;
+    }
+    let described = match picker.description_show_mode {
+        DescriptionShowMode::Never => false,
+        _ => opts.iter().any(|item| item.description.is_some()),
+    };
+    if !described && grid_columns(opts, width) >= 2 {
+        Layout::Grid
+    } else {
+        Layout::List
+    }
+}
+
+/// Build the frame of the text input, and the cursor is placed at the `cursor`-th character of `text`.
+pub(crate) fn input(prompt: &str, text: &[char], cursor: usize, width: usize) -> Frame {
+    let head = format!("{prompt} {}", text[..cursor].iter().collect::<String>());
+    let tail = text[cursor..].iter().collect::<String>();
+    let lines = wrap(&format!("{head}{tail}"), width);
+    let head_width = head.width();
+    let cursor = match head_width.checked_div(width) {
+        Some(row) => (row, head_width % width),
+        None => (0, head_width),
+    };
+    Frame { lines, cursor: Some(cursor), ..Frame::default() }
+}
+
+/// Build the summary line replacing the picker after answering, if [Picker::summary] is set.
+pub(crate) fn summary(picker: &Picker, prompt: &str, outcome: &Outcome<String>) -> Option<String> {
+    picker.summary.as_ref().map(|summary| summary_line(summary, prompt, outcome))
+}
+
+/// Build the summary line of the given format.
+pub(crate) fn summary_line(summary: &Summary, prompt: &str, outcome: &Outcome<String>) -> String {
+    let line = match outcome {
+        Outcome::Selected(answer) => summary.selected.replace("{answer}", answer),
+        _ => summary.cancelled.clone(),
+    };
+    line.replace("{prompt}", prompt)
+}
+
+/// Placeholders available in [Picker::template].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Placeholder {
+    Prompt,
+    Options,
+    Default,
+    Description,
+    Hint,
+}
+
+impl Placeholder {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "prompt" => Some(Placeholder::Prompt),
+            "options" => Some(Placeholder::Options),
+            "default" => Some(Placeholder::Default),
+            "description" => Some(Placeholder::Description),
+            "hint" => Some(Placeholder::Hint),
+            _ => None,
+        }
+    }
+}
+
+/// Segment of the parsed template, a literal text or a placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Segment {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// Parse the template of the prompt line into the segments.
+/// `{{` and `}}` are the escaped braces.
+/// Returns the reason as `Err` if the template has an unknown placeholder or an unbalanced brace.
+pub(crate) fn parse_template(template: &str) -> std::result::Result<Vec<Segment>, String> {
+    let mut segments = vec![];
+    let mut text = String::new();
+    let mut chars = template.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.as_str().starts_with('{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.as_str().starts_with('}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let rest = chars.as_str();
+                let end = rest.find('}').ok_or_else(|| "unclosed '{'".to_string())?;
+                let name = &rest[..end];
+                let placeholder = Placeholder::from_name(name)
+                    .ok_or_else(|| format!("unknown placeholder {{{name}}}"))?;
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(Segment::Placeholder(placeholder));
+                chars = rest[end + 1..].chars();
+            }
+            '}' => return Err("unmatched '}'".to_string()),
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// Build the prompt line by expanding [Picker::template].
+/// If `inline` is `false`, `{options}` is expanded to the empty string, since the items are shown in the rows below,
+/// and the trailing spaces of the lines are trimmed.
+/// An invalid template (only possible by modifying the field after the build) is shown as it is.
+fn prompt_line(picker: &Picker, prompt: &str, opts: &Options, default: usize, inline: bool) -> String {
+    let segments = parse_template(&picker.template)
+        .unwrap_or_else(|_| vec![Segment::Text(picker.template.clone())]);
+    let line = segments.iter().map(|segment| match segment {
+        Segment::Text(text) => text.clone(),
+        Segment::Placeholder(Placeholder::Prompt) => prompt.to_string(),
+        Segment::Placeholder(Placeholder::Options) if !inline => "".to_string(),
+        Segment::Placeholder(Placeholder::Options) => {
+            let (paren_left, paren_right) = paren_strings(picker);
+            format!("{paren_left}{}{paren_right}", opts.display(picker))
+        }
+        Segment::Placeholder(Placeholder::Default) => opts.items[default].long_label.clone(),
+        Segment::Placeholder(Placeholder::Description) => opts.current_item().description.clone().unwrap_or_default(),
+        Segment::Placeholder(Placeholder::Hint) => opts.iter()
+            .map(|item| item.key.to_string())
+            .collect::<Vec<_>>().join(&picker.delimiter),
+    }).collect::<String>();
+    if inline {
+        line
+    } else {
+        line.split('\n').map(str::trim_end).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Build the rows of [Layout::List], such as `> [m] Maybe — I haven't tried it yet`.
+fn list_rows(picker: &Picker, opts: &Options, width: usize, height: usize) -> Vec<String> {
+    let name_width = calculate_name_width(picker, opts);
+    let blocks = opts.iter().enumerate()
+        .map(|(index, item)| {
+            let selected = if opts.current == index { ">" } else { " " };
+            let description = match (&picker.description_show_mode, &item.description) {
+                (DescriptionShowMode::All, Some(d)) => Some(d),
+                (DescriptionShowMode::CurrentOnly, Some(d)) if opts.current == index => Some(d),
+                _ => None,
+            };
+            match description {
+                Some(d) => {
+                    let head = format!("{selected} [{}] {} — ", item.key, pad(&item.long_label, name_width));
+                    wrap_hanging(&head, d, width)
+                }
+                None => wrap(&format!("{selected} [{}] {}", item.key, item.long_label), width),
+            }
+        }).collect::<Vec<_>>();
+    let (start, end) = viewport(&blocks, opts.current, height);
+    blocks[start..end].concat()
+}
+
+/// The cell of [Layout::Grid] for the given item, such as `> [m] Maybe`.
+fn grid_cell(opts: &Options, index: usize) -> String {
+    let selected = if opts.current == index { ">" } else { " " };
+    let item = &opts.items[index];
+    format!("{selected} [{}] {}", item.key, item.long_label)
+}
+
+/// The spaces between the columns of [Layout::Grid].
+const GRID_GAP: usize = 2;
+
+/// Returns the number of the columns of [Layout::Grid] fitting in the given width, at least one.
+pub(crate) fn grid_columns(opts: &Options, width: usize) -> usize {
+    let cell_width = (0..opts.items.len()).map(|index| grid_cell(opts, index).width()).max().unwrap_or(0);
+    ((width + GRID_GAP) / (cell_width + GRID_GAP)).clamp(1, opts.items.len())
+}
+
+/// Build the rows of [Layout::Grid], the items are placed from left to right, and then top to bottom.
+fn grid_rows(opts: &Options, width: usize, height: usize) -> Vec<String> {
+    let columns = grid_columns(opts, width);
+    let cells = (0..opts.items.len()).map(|index| grid_cell(opts, index)).collect::<Vec<_>>();
+    let cell_width = cells.iter().map(|cell| cell.width()).max().unwrap_or(0);
+    let blocks = cells.chunks(columns)
+        .map(|row| {
+            let line = row.iter()
+                .map(|cell| pad(cell, cell_width))
+                .collect::<Vec<_>>().join(&" ".repeat(GRID_GAP));
+            wrap(line.trim_end(), width)
+        }).collect::<Vec<_>>();
+    let (start, end) = viewport(&blocks, opts.current / columns, height);
+    blocks[start..end].concat()
+}
+
+pub(crate) fn paren_strings(picker: &Picker) -> (String, String) {
+    match &picker.paren {
+        Some((left, right)) => (left.clone(), right.clone()),
+        None => ("".to_string(), "".to_string()),
+    }
+}
+
+fn descriptions(picker: &Picker, opts: &Options, width: usize, height: usize) -> Vec<String> {
+    let name_width = calculate_name_width(picker, opts);
+    match picker.description_show_mode {
+        DescriptionShowMode::All => all_descriptions(opts, name_width, width, height),
+        DescriptionShowMode::CurrentOnly => current_description(opts, name_width, width),
+        DescriptionShowMode::Never => vec![],
+    }
+}
+
+pub(crate) fn calculate_name_width(picker: &Picker, opts: &Options) -> usize {
+    use DescriptionNameWidth::*;
+    match picker.description_name_width {
+        Fixed(w) => w,
+        Never => 0,
+        Auto => opts.iter().map(|item| item.long_label.width()).max().unwrap_or(0),
+    }
+}
+
+fn current_description(opts: &Options, name_width: usize, width: usize) -> Vec<String> {
+    let item = opts.current_item();
+    let head = format!("    {} ", pad(&item.long_label, name_width));
+    wrap_hanging(&head, item.description.as_deref().unwrap_or(""), width)
+}
+
+fn all_descriptions(opts: &Options, name_width: usize, width: usize, height: usize) -> Vec<String> {
+    let blocks = opts.iter().enumerate()
+        .map(|(index, item)| {
+            let selected = if opts.current == index { ">" } else { " " };
+            let head = format!("{:1} {} ", selected, pad(&item.long_label, name_width));
+            wrap_hanging(&head, item.description.as_deref().unwrap_or(""), width)
+        }).collect::<Vec<_>>();
+    let (start, end) = viewport(&blocks, opts.current, height);
+    blocks[start..end].concat()
+}
+
+/// Returns the range of the blocks fitting in `height` rows, including the `current` block.
+/// The range is expanded below and above the current block alternately.
+fn viewport(blocks: &[Vec<String>], current: usize, height: usize) -> (usize, usize) {
+    let total = blocks.iter().map(Vec::len).sum::<usize>();
+    if total <= height {
+        return (0, blocks.len());
+    }
+    let (mut start, mut end) = (current, current + 1);
+    let mut rows = blocks[current].len();
+    loop {
+        let mut grown = false;
+        if end < blocks.len() && rows + blocks[end].len() <= height {
+            rows += blocks[end].len();
+            end += 1;
+            grown = true;
+        }
+        if start > 0 && rows + blocks[start - 1].len() <= height {
+            rows += blocks[start - 1].len();
+            start -= 1;
+            grown = true;
+        }
+        if !grown {
+            return (start, end);
+        }
+    }
+}
+
+/// Pad the given string with spaces to the given display width.
+fn pad(s: &str, width: usize) -> String {
+    let w = s.width();
+    if w >= width {
+        s.to_string()
+    } else {
+        format!("{s}{}", " ".repeat(width - w))
+    }
+}
+
+/// Wrap the given string into the lines of the given display width.
+/// Line breaks (`\n`) in the string are also respected.
+/// If `width` is zero, the string is only split by the line breaks.
+pub(crate) fn wrap(s: &str, width: usize) -> Vec<String> {
+    let mut lines = vec![];
+    for line in s.split('\n') {
+        let mut current = String::new();
+        let mut current_width = 0;
+        for c in line.chars() {
+            let w = c.width().unwrap_or(0);
+            if width > 0 && current_width + w > width && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            current.push(c);
+            current_width += w;
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+/// Wrap `body` at word boundaries with a hanging indent, that is, the first line starts with `head`,
+/// and the following lines are indented by the display width of `head`.
+/// Line breaks (`\n`) in `body` start new indented lines, and words longer than the line are split.
+/// If the indent leaves no room in `width`, this falls back to [wrap].
+fn wrap_hanging(head: &str, body: &str, width: usize) -> Vec<String> {
+    let indent = head.width();
+    if width == 0 || indent >= width {
+        return wrap(&format!("{head}{body}"), width);
+    }
+    let room = width - indent;
+    let mut lines = vec![];
+    for paragraph in body.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split(' ') {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{line} {word}") };
+            if candidate.width() <= room {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            let mut pieces = wrap(word, room);
+            line = pieces.pop().unwrap_or_default();
+            lines.extend(pieces);
+        }
+        lines.push(line);
+    }
+    lines.into_iter().enumerate()
+        .map(|(index, line)| {
+            let prefix = if index == 0 { head.to_string() } else { " ".repeat(indent) };
+            format!("{prefix}{line}").trim_end().to_string()
+        }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    mod paren_strings {
+        use super::super::*;
+
+        #[test]
+        fn test_paren_strings() {
+            let picker = crate::PickerBuilder::default().paren("()").build().unwrap();
+            let (left, right) = paren_strings(&picker);
+            assert_eq!(left, "(".to_string());
+            assert_eq!(right, ")".to_string());
+        }
+
+        #[test]
+        fn test_empty_paren() {
+            let picker = crate::PickerBuilder::default().paren("").build().unwrap();
+            let (left, right) = paren_strings(&picker);
+            assert_eq!(left, "".to_string());
+            assert_eq!(right, "".to_string());
+        }
+
+
+        #[test]
+        fn test_only_left() {
+            let picker = crate::PickerBuilder::default().paren(":").build().unwrap();
+            let (left, right) = paren_strings(&picker);
+            assert_eq!(left, ":".to_string());
+            assert_eq!(right, "".to_string());
+        }
+
+        #[test]
+        fn test_() {
+            let picker = crate::PickerBuilder::default().paren("(<>)").build().unwrap();
+            let (left, right) = paren_strings(&picker);
+            assert_eq!(left, "(<".to_string());
+            assert_eq!(right, ">)".to_string());
+        }
+    }
+
+    mod calculate_name_width {
+        use crate::{OptionsBuilder, PickerBuilder};
+
+        #[test]
+        fn test_fixed_width() {
+            let picker = PickerBuilder::default()
+                .description_name_width(crate::DescriptionNameWidth::Fixed(7))
+                .build()
+                .unwrap();
+            let options = OptionsBuilder::default()
+                .item(crate::Item::new_full("Short", "s", 's', None))
+                .item(crate::Item::new_full("LongerName", "l", 'l', None))
+                .build()
+                .unwrap();
+            let width = crate::render::calculate_name_width(&picker, &options);
+            assert_eq!(width, 7);
+        }
+
+        #[test]
+        fn test_auto_width() {
+            let picker = PickerBuilder::default()
+                .description_name_width(crate::DescriptionNameWidth::Auto)
+                .build()
+                .unwrap();
+            let options = OptionsBuilder::default()
+                .item(crate::Item::new_full("Short", "s", 's', None))
+                .item(crate::Item::new_full("LongerName", "l", 'l', None))
+                .build()
+                .unwrap();
+            let width = crate::render::calculate_name_width(&picker, &options);
+            assert_eq!(width, "LongerName".len());
+        }
+
+        #[test]
+        fn test_never_width() {
+            let picker = PickerBuilder::default()
+                .description_name_width(crate::DescriptionNameWidth::Never)
+                .build()
+                .unwrap();
+            let options = OptionsBuilder::default()
+                .item(crate::Item::new_full("Short", "s", 's', None))
+                .item(crate::Item::new_full("LongerName", "l", 'l', None))
+                .build()
+                .unwrap();
+            let width = crate::render::calculate_name_width(&picker, &options);
+            assert_eq!(width, 0);
+        }
+    }
+
+    mod wrap {
+        use super::super::*;
+
+        #[test]
+        fn test_wrap_by_width() {
+            assert_eq!(wrap("Do you like Rust?", 8), vec!["Do you l", "ike Rust", "?"]);
+        }
+
+        #[test]
+        fn test_wrap_wide_chars() {
+            assert_eq!(wrap("ラスト好き", 4), vec!["ラス", "ト好", "き"]);
+        }
+
+        #[test]
+        fn test_wrap_line_breaks() {
+            assert_eq!(wrap("first\nsecond", 0), vec!["first", "second"]);
+        }
+
+        #[test]
+        fn test_wrap_hanging_by_words() {
+            assert_eq!(wrap_hanging("> Maybe ", "I haven't tried it yet", 20), vec![
+                "> Maybe I haven't",
+                "        tried it yet",
+            ]);
+        }
+
+        #[test]
+        fn test_wrap_hanging_line_breaks_and_long_words() {
+            assert_eq!(wrap_hanging("  No ", "first\nabcdefghijklmn", 10), vec![
+                "  No first",
+                "     abcde",
+                "     fghij",
+                "     klmn",
+            ]);
+        }
+
+        #[test]
+        fn test_wrap_hanging_without_room() {
+            assert_eq!(wrap_hanging("> LongName ", "desc", 8), vec!["> LongNa", "me desc"]);
+        }
+    }
+
+    mod parse_template {
+        use super::super::*;
+
+        #[test]
+        fn test_placeholders_and_text() {
+            assert_eq!(parse_template("? {prompt}\n{options} {hint}").unwrap(), vec![
+                Segment::Text("? ".to_string()),
+                Segment::Placeholder(Placeholder::Prompt),
+                Segment::Text("\n".to_string()),
+                Segment::Placeholder(Placeholder::Options),
+                Segment::Text(" ".to_string()),
+                Segment::Placeholder(Placeholder::Hint),
+            ]);
+        }
+
+        #[test]
+        fn test_escaped_braces() {
+            assert_eq!(parse_template("{{{default}}}").unwrap(), vec![
+                Segment::Text("{".to_string()),
+                Segment::Placeholder(Placeholder::Default),
+                Segment::Text("}".to_string()),
+            ]);
+        }
+
+        #[test]
+        fn test_invalid_templates() {
+            assert_eq!(parse_template("{prompt} {choices}"), Err("unknown placeholder {choices}".to_string()));
+            assert_eq!(parse_template("{prompt"), Err("unclosed '{'".to_string()));
+            assert_eq!(parse_template("prompt}"), Err("unmatched '}'".to_string()));
+        }
+    }
+
+    mod choice {
+        use crate::{item, DescriptionShowMode, Footer, Layout, OptionsBuilder, PickerBuilder, Preview, PreviewPosition};
+
+        fn options(current: usize) -> crate::Options {
+            OptionsBuilder::default()
+                .item(item!("Yes", "y", "I love it"))
+                .item(item!("So so", description = "I like it, but sometimes it's hard"))
+                .item(item!("Maybe", key = 'm', description = "I haven't tried it yet"))
+                .item(item!("No", "n", "I don't like it"))
+                .current(current)
+                .build().unwrap()
+        }
+
+        #[test]
+        fn test_all_descriptions() {
+            let picker = PickerBuilder::default()
+                .description_show_mode(DescriptionShowMode::All)
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(2), 2, 80, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust? y/s/ Maybe /n",
+                "  Yes   I love it",
+                "  So so I like it, but sometimes it's hard",
+                "> Maybe I haven't tried it yet",
+                "  No    I don't like it",
+            ]);
+        }
+
+        #[test]
+        fn test_scroll_descriptions_in_height() {
+            let picker = PickerBuilder::default()
+                .description_show_mode(DescriptionShowMode::All)
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(3), 3, 80, 3);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust? y/s/m/ No ",
+                "  Maybe I haven't tried it yet",
+                "> No    I don't like it",
+            ]);
+        }
+
+        #[test]
+        fn test_template() {
+            let picker = PickerBuilder::default()
+                .template("? {prompt} [{default}]\n{options} ({description})")
+                .unselected_display(crate::UnselectedDisplay::Hidden)
+                .selected_decoration("> ", "")
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(2), 0, 80, 24);
+            assert_eq!(frame.lines, vec![
+                "? Do you like Rust? [Yes]",
+                "> Maybe (I haven't tried it yet)",
+            ]);
+        }
+
+        #[test]
+        fn test_list_layout() {
+            let picker = PickerBuilder::default()
+                .layout(Layout::List)
+                .description_show_mode(DescriptionShowMode::All)
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(2), 2, 80, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust?",
+                "  [y] Yes   — I love it",
+                "  [s] So so — I like it, but sometimes it's hard",
+                "> [m] Maybe — I haven't tried it yet",
+                "  [n] No    — I don't like it",
+            ]);
+        }
+
+        #[test]
+        fn test_grid_layout() {
+            let picker = PickerBuilder::default()
+                .layout(Layout::Grid)
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(2), 2, 30, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust?",
+                "  [y] Yes      [s] So so",
+                "> [m] Maybe    [n] No",
+            ]);
+        }
+
+        #[test]
+        fn test_auto_layout() {
+            let picker = PickerBuilder::default()
+                .layout(Layout::Auto)
+                .build().unwrap();
+            let opts = options(0);
+            assert_eq!(super::super::layout(&picker, "Do you like Rust?", &opts, 0, 80), Layout::Inline);
+            assert_eq!(super::super::layout(&picker, "Do you like Rust?", &opts, 0, 24), Layout::Grid);
+            assert_eq!(super::super::layout(&picker, "Do you like Rust?", &opts, 0, 12), Layout::List);
+            let picker = PickerBuilder::default()
+                .layout(Layout::Auto)
+                .description_show_mode(DescriptionShowMode::CurrentOnly)
+                .build().unwrap();
+            assert_eq!(super::super::layout(&picker, "Do you like Rust?", &opts, 0, 24), Layout::List);
+        }
+
+        #[test]
+        fn test_wrapped_descriptions_with_hanging_indent() {
+            let picker = PickerBuilder::default()
+                .description_show_mode(DescriptionShowMode::All)
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(1), 1, 30, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust? y/ So so /m/",
+                "n",
+                "  Yes   I love it",
+                "> So so I like it, but",
+                "        sometimes it's hard",
+                "  Maybe I haven't tried it yet",
+                "  No    I don't like it",
+            ]);
+        }
+
+        fn preview_lines() -> Vec<String> {
+            (1..=5).map(|n| format!("line {n}")).collect()
+        }
+
+        #[test]
+        fn test_preview_below() {
+            let picker = PickerBuilder::default()
+                .preview(Preview::new(|_| String::new()).height(3))
+                .build().unwrap();
+            let frame = super::super::choice_with_preview(&picker, "Do you like Rust?", &options(0), 0, (&preview_lines(), 1), 12, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like ",
+                "Rust?  Yes /",
+                "s/m/n",
+                "────────────",
+                "line 2",
+                "line 3",
+                "line 4",
+            ]);
+        }
+
+        #[test]
+        fn test_preview_beside_clamps_scroll() {
+            let picker = PickerBuilder::default()
+                .preview(Preview::new(|_| String::new()).position(PreviewPosition::Beside).height(2))
+                .build().unwrap();
+            let frame = super::super::choice_with_preview(&picker, "Rust?", &options(0), 0, (&preview_lines(), 9), 30, 24);
+            assert_eq!(frame.lines, vec![
+                "Rust?  Yes /s/m │ line 4",
+                "/n              │ line 5",
+            ]);
+        }
+
+        #[test]
+        fn test_current_description_with_fixed_name_width() {
+            let picker = PickerBuilder::default()
+                .description_show_mode(DescriptionShowMode::CurrentOnly)
+                .description_name_width(crate::DescriptionNameWidth::Fixed(8))
+                .build().unwrap();
+            let opts = OptionsBuilder::default()
+                .item(item!("日本語", "j", "Japanese, written in kanji and kana"))
+                .item(item!("English", "e", "English"))
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Language?", &opts, 0, 30, 24);
+            assert_eq!(frame.lines, vec![
+                "Language?  日本語 /e",
+                "    日本語   Japanese, written",
+                "             in kanji and kana",
+            ]);
+        }
+
+        #[test]
+        fn test_footer() {
+            let picker = PickerBuilder::default()
+                .footer(Footer::default())
+                .build().unwrap();
+            assert_eq!(super::super::footer(&picker, "Do you like Rust?", &options(0), 0, 80), vec![
+                "←/→ move · enter select · esc cancel · y/s/m/n jump · ? help",
+            ]);
+            let footer = Footer {
+                move_label: "移動".to_string(),
+                separator: ", ".to_string(),
+                ..Footer::default()
+            };
+            let picker = PickerBuilder::default()
+                .layout(Layout::List)
+                .footer(footer)
+                .build().unwrap();
+            assert_eq!(super::super::footer(&picker, "Do you like Rust?", &options(0), 0, 80), vec![
+                "↑/↓ 移動, enter select, esc cancel, y/s/m/n jump, ? help",
+            ]);
+        }
+
+        #[test]
+        fn test_footer_text() {
+            let picker = PickerBuilder::default()
+                .footer(Footer::text("press y or n"))
+                .build().unwrap();
+            assert_eq!(super::super::footer(&picker, "Do you like Rust?", &options(0), 0, 80), vec!["press y or n"]);
+            let picker = PickerBuilder::default().build().unwrap();
+            assert!(super::super::footer(&picker, "Do you like Rust?", &options(0), 0, 80).is_empty());
+        }
+
+        #[test]
+        fn test_help() {
+            let picker = PickerBuilder::default().build().unwrap();
+            let (frame, page) = super::super::help(&picker, "Do you like Rust?", &options(2), 0, 80, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust? (help)",
+                "  ←/→ ↑/↓  move",
+                "  enter    select",
+                "  esc      cancel",
+                "  ctrl+c   interrupt",
+                "  ctrl+d   end of input",
+                "  ctrl+z   suspend",
+                "  ?        help",
+                "Maybe: I haven't tried it yet",
+                "  y        Yes",
+                "  s        So so",
+                "  m        Maybe",
+                "  n        No",
+                "press any key to return",
+            ]);
+            assert_eq!(page, super::super::HelpPage { rows: 4, max: 0 });
+        }
+
+        #[test]
+        fn test_help_scrolls_item_keys() {
+            let footer = Footer { move_label: "移動".to_string(), scroll_label: "スクロール".to_string(), ..Footer::default() };
+            let picker = PickerBuilder::default().footer(footer).build().unwrap();
+            let (frame, page) = super::super::help(&picker, "Do you like Rust?", &options(2), 1, 80, 12);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust? (help)",
+                "  ←/→ ↑/↓  移動",
+                "  enter    select",
+                "  esc      cancel",
+                "  ctrl+c   interrupt",
+                "  ctrl+d   end of input",
+                "  ctrl+z   suspend",
+                "  ?        help",
+                "Maybe: I haven't tried it yet",
+                "  s        So so",
+                "  m        Maybe",
+                "↑/↓ pgup/pgdn スクロール · any other key to return",
+            ]);
+            assert_eq!(page, super::super::HelpPage { rows: 2, max: 2 });
+            let (frame, _) = super::super::help(&picker, "Do you like Rust?", &options(2), 5, 80, 12);
+            assert_eq!(frame.lines[9..11], ["  m        Maybe", "  n        No"]);
+        }
+
+        #[test]
+        fn test_wrap_in_narrow_width() {
+            let picker = PickerBuilder::default()
+                .description_show_mode(DescriptionShowMode::CurrentOnly)
+                .description_name_width(crate::DescriptionNameWidth::Fixed(6))
+                .build().unwrap();
+            let frame = super::super::choice(&picker, "Do you like Rust?", &options(0), 0, 20, 24);
+            assert_eq!(frame.lines, vec![
+                "Do you like Rust?  Y",
+                "es /s/m/n",
+                "    Yes    I love it",
+            ]);
+        }
+    }
+}
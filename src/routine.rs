@@ -1,24 +1,30 @@
 //! Routine for handling user choice interactions.
-use crate::{Options, Picker, screen};
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
-use crossterm::{cursor, queue, terminal};
+use crate::{CancelReason, Error, InvalidKeyFeedback, Layout, OnTimeout, Options, Outcome, Picker, Policy, Result, preseed, render, screen};
+use crate::render::Frame;
+use crate::screen::{Canvas, Next, Terminal};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use std::cell::{Cell, RefCell};
 use std::io::{IsTerminal, Write};
+use std::time::{Duration, Instant};
 
 enum Action {
     Cancel,
+    Interrupt,
+    Eof,
+    Suspend,
     Confirm,
     Continue(usize),
+    /// The key matches no item.
+    Invalid(char),
     Next,
     Previous,
 }
 
 /// Ensure that both stdin and stdout are TTYs.
-fn ensure_tty(stdout: std::io::Stdout) -> std::io::Result<std::io::Stdout> {
+fn ensure_tty(stdout: std::io::Stdout) -> Result<std::io::Stdout> {
     log::info!("Ensuring TTY for stdin and stdout");
     if !stdout.is_terminal() || !std::io::stdin().is_terminal() {
-        Err(std::io::Error::other(
-            "not running on a TTY (interactive input is unavailable)",
-        ))
+        Err(Error::NotATty)
     } else {
         Ok(stdout)
     }
@@ -28,95 +34,767 @@ pub(crate) fn choose(
     picker: &mut Picker,
     prompt: &str,
     options: Options,
-) -> std::io::Result<Option<String>> {
+    id: Option<&str>,
+) -> Result<Outcome<String>> {
+    if let Some(outcome) = answer_choice(picker, prompt, &options, id)? {
+        return Ok(outcome);
+    }
+    let outcome = run_choose(picker, prompt, options)?;
+    finish(picker, prompt, outcome)
+}
+
+/// Same as [choose], but waits for the key events without blocking the thread.
+#[cfg(feature = "async")]
+pub(crate) async fn choose_async(
+    picker: &mut Picker,
+    prompt: &str,
+    options: Options,
+    id: Option<&str>,
+) -> Result<Outcome<String>> {
+    if let Some(outcome) = answer_choice(picker, prompt, &options, id)? {
+        return Ok(outcome);
+    }
+    let outcome = run_choose_async(picker, prompt, options).await?;
+    finish(picker, prompt, outcome)
+}
+
+/// Answer the choice without the prompt by the preseeded answer or the [Policy], if any.
+fn answer_choice(picker: &Picker, prompt: &str, options: &Options, id: Option<&str>) -> Result<Option<Outcome<String>>> {
+    if let Some(id) = id
+        && let Some(answer) = preseed::lookup(picker, id)?
+    {
+        let label = preseed::resolve(options, id, &answer)?;
+        log::info!("Answered {id} with the preseeded answer: {label}");
+        return Ok(Some(Outcome::Selected(label)));
+    }
+    assume(picker, prompt, || options.current_name())
+}
+
+/// Answer the prompt without rendering according to the [Policy].
+/// Returns `None` for [Policy::Interactive], and then the prompt should be asked.
+fn assume(picker: &Picker, prompt: &str, default: impl FnOnce() -> String) -> Result<Option<Outcome<String>>> {
+    match picker.effective_policy() {
+        Policy::Interactive => Ok(None),
+        Policy::FailIfInteractive => Err(Error::InteractionRequired { prompt: prompt.to_string() }),
+        policy => {
+            let answer = default();
+            log::info!("Auto-answered \"{prompt}\" with \"{answer}\" by {policy:?}");
+            Ok(Some(Outcome::Selected(answer)))
+        }
+    }
+}
+
+/// Print the summary line in the alternate screen mode (unless [Picker::ephemeral]),
+/// since the picker region has gone with the alternate screen,
+/// and re-raise SIGINT for [Outcome::Interrupted] if [Picker::reraise_interrupt] is set.
+/// This function must be called after the screen is dropped, that is, the terminal is restored.
+fn finish(picker: &Picker, prompt: &str, outcome: Outcome<String>) -> Result<Outcome<String>> {
+    if picker.alternate_screen
+        && !picker.ephemeral
+        && let Some(line) = render::summary(picker, prompt, &outcome)
+    {
+        let mut stdout = std::io::stdout();
+        writeln!(stdout, "{line}")?;
+        stdout.flush()?;
+    }
+    if picker.reraise_interrupt && matches!(outcome, Outcome::Interrupted) {
+        log::info!("Re-raising SIGINT");
+        reraise_interrupt();
+    }
+    Ok(outcome)
+}
+
+#[cfg(unix)]
+fn reraise_interrupt() {
+    // if the caller has a handler of SIGINT, the process continues and returns Outcome::Interrupted.
+    let _ = signal_hook::low_level::raise(signal_hook::consts::SIGINT);
+}
+
+#[cfg(not(unix))]
+fn reraise_interrupt() {
+    std::process::exit(130);
+}
+
+fn run_choose(
+    picker: &mut Picker,
+    prompt: &str,
+    options: Options,
+) -> Result<Outcome<String>> {
     let mut stdout = ensure_tty(std::io::stdout())?;
-    let mut guard = screen::new(picker, &options, &mut stdout)?;
-    let mut opts = options;
-    let (paren_left, paren_right) = paren_strings(picker);
+    let mut screen = screen::open(picker, &mut stdout)?;
+    let mut canvas = Canvas::new(picker.alternate_screen, picker.ephemeral);
     log::info!("Starting choice loop");
+    let mut choice = Choice::new(picker, prompt, options);
+    run_loop(&mut choice, &mut screen, &mut canvas, &mut stdout)
+}
+
+#[cfg(feature = "async")]
+async fn run_choose_async(
+    picker: &mut Picker,
+    prompt: &str,
+    options: Options,
+) -> Result<Outcome<String>> {
+    let mut stdout = ensure_tty(std::io::stdout())?;
+    let mut screen = screen::AsyncScreen::new(screen::open(picker, &mut stdout)?);
+    let mut canvas = Canvas::new(picker.alternate_screen, picker.ephemeral);
+    log::info!("Starting async choice loop");
+    let mut choice = Choice::new(picker, prompt, options);
+    run_loop_async(&mut choice, &mut screen, &mut canvas, &mut stdout).await
+}
+
+/// Widget is the state of a prompt, which is rendered into a frame and updated by the key events.
+pub(crate) trait Widget {
+    type Output;
+
+    fn frame(&self, width: usize, height: usize) -> Frame;
+
+    fn handle(&mut self, key: KeyEvent) -> Step<Self::Output>;
+
+    /// Returns the line replacing the frame after the widget is done, if any.
+    fn summary(&self, outcome: &Outcome<Self::Output>) -> Option<String>;
 
+    /// Returns the time when [Widget::tick] should be called, if any.
+    fn deadline(&self) -> Option<Instant> {
+        None
+    }
+
+    /// Called when the deadline has passed without key events.
+    fn tick(&mut self) -> Step<Self::Output> {
+        Step::Continue
+    }
+}
+
+/// Step is the result of handling a key event by a [Widget].
+pub(crate) enum Step<T> {
+    Continue,
+    Suspend,
+    Done(Outcome<T>),
+}
+
+/// Run the event loop of the given widget until it is done.
+/// Resizing the terminal re-layouts the widget, and the whole frame is redrawn.
+fn run_loop<G: Widget, T: Terminal, W: Write>(
+    widget: &mut G,
+    terminal: &mut T,
+    canvas: &mut Canvas,
+    out: &mut W,
+) -> Result<Outcome<G::Output>> {
+    let mut size = terminal.size()?;
     loop {
-        guard.prepare_write(&mut stdout)?;
-        print!(
-            "{prompt} {paren_left}{}{paren_right}",
-            &opts.display(picker)
-        );
-        print_description(picker, &mut stdout, &opts);
-        stdout.flush()?;
+        canvas.draw(out, &widget.frame(size.0, size.1))?;
+        let next = terminal.next_event(widget.deadline())?;
+        if let Some(outcome) = advance(widget, terminal, canvas, out, &mut size, next)? {
+            return Ok(outcome);
+        }
+    }
+}
 
-        if let Event::Key(key_event) = event::read()? {
-            opts = match process_key(key_event.code, key_event.modifiers, &opts) {
-                Action::Confirm => return Ok(Some(opts.current_name())),
-                Action::Cancel => return Ok(None),
-                Action::Continue(new_current) => opts.update_current(new_current),
-                Action::Next => {
-                    let new_index = opts.next(picker);
-                    opts.update_current(new_index)
-                }
-                Action::Previous => {
-                    let new_index = opts.previous(picker);
-                    opts.update_current(new_index)
-                }
+/// Same as [run_loop], but waits for the events without blocking the thread.
+/// If the returned future is dropped, the loop stops at the await point,
+/// and the terminal is restored by dropping the screen.
+#[cfg(feature = "async")]
+async fn run_loop_async<G: Widget, T: screen::AsyncTerminal, W: Write>(
+    widget: &mut G,
+    terminal: &mut T,
+    canvas: &mut Canvas,
+    out: &mut W,
+) -> Result<Outcome<G::Output>> {
+    let mut size = terminal.size()?;
+    loop {
+        canvas.draw(out, &widget.frame(size.0, size.1))?;
+        let next = terminal.next_event_async(widget.deadline()).await?;
+        if let Some(outcome) = advance(widget, terminal, canvas, out, &mut size, next)? {
+            return Ok(outcome);
+        }
+    }
+}
+
+/// Apply the next event of the terminal to the widget, and returns the outcome if the widget is done.
+/// `size` is the terminal size in (columns, rows), updated on resizing.
+fn advance<G: Widget, T: Terminal, W: Write>(
+    widget: &mut G,
+    terminal: &mut T,
+    canvas: &mut Canvas,
+    out: &mut W,
+    size: &mut (usize, usize),
+    next: Next,
+) -> Result<Option<Outcome<G::Output>>> {
+    let step = match next {
+        Next::Resumed => {
+            canvas.reset();
+            return Ok(None);
+        }
+        Next::Timeout => widget.tick(),
+        Next::Aborted => Step::Done(Outcome::Aborted),
+        Next::Print(lines) => {
+            canvas.print_above(out, &lines)?;
+            return Ok(None);
+        }
+        Next::Hold => {
+            canvas.erase(out)?;
+            terminal.hold()?;
+            canvas.reset();
+            return Ok(None);
+        }
+        Next::Event(Event::Resize(columns, rows)) => {
+            log::info!("Resized to {columns}x{rows}");
+            *size = (columns as usize, rows as usize);
+            canvas.resize(out)?;
+            return Ok(None);
+        }
+        Next::Event(Event::Key(key_event)) => widget.handle(key_event),
+        Next::Event(_) => return Ok(None),
+    };
+    match step {
+        Step::Continue => Ok(None),
+        Step::Suspend => {
+            terminal.suspend()?;
+            canvas.reset();
+            Ok(None)
+        }
+        Step::Done(outcome) => {
+            let summary = widget.summary(&outcome).map(|line| render::wrap(&line, size.0));
+            canvas.finish(out, summary.as_deref())?;
+            Ok(Some(outcome))
+        }
+    }
+}
+
+/// How long the flash of [InvalidKeyFeedback::Flash] lasts.
+const FLASH_DURATION: Duration = Duration::from_millis(150);
+/// How long the message of [InvalidKeyFeedback::Message] is shown, unless another key is pressed.
+const MESSAGE_DURATION: Duration = Duration::from_secs(2);
+
+/// Feedback is the transient state shown for an invalid key until `until`.
+struct Feedback {
+    message: Option<String>,
+    flash: bool,
+    until: Instant,
+}
+
+/// Choice is the widget for choosing an option from [Options].
+pub(crate) struct Choice<'a> {
+    picker: &'a Picker,
+    prompt: &'a str,
+    pub(crate) opts: Options,
+    default: usize,
+    /// The width of the options pane in the last frame, for moving in [Layout::Grid].
+    width: Cell<usize>,
+    /// The preview lines and the index of the item they are built for, refreshed when the current item changes.
+    preview: RefCell<Option<(usize, Vec<String>)>>,
+    /// The first line of the preview lines shown in the pane.
+    preview_scroll: usize,
+    /// Whether the help overlay is shown instead of the picker.
+    help: bool,
+    /// The first row of the item keys shown in the help overlay.
+    help_scroll: usize,
+    /// The item keys shown in the help overlay in the last frame, for scrolling them.
+    help_page: Cell<render::HelpPage>,
+    /// Whether to ring the bell in the next frame, for [InvalidKeyFeedback::Bell].
+    bell: Cell<bool>,
+    /// The feedback for the invalid key shown now.
+    feedback: Option<Feedback>,
+    /// When the countdown of [Picker::timeout] expires, or `None` if no countdown is running.
+    expiry: Option<Instant>,
+}
+
+impl Widget for Choice<'_> {
+    type Output = String;
+
+    fn frame(&self, width: usize, height: usize) -> Frame {
+        self.width.set(render::options_width(self.picker, width));
+        if self.help {
+            let (frame, page) = render::help(self.picker, self.prompt, &self.opts, self.help_scroll, width, height);
+            self.help_page.set(page);
+            return frame;
+        }
+        let mut footer = match self.feedback.as_ref().and_then(|feedback| feedback.message.as_ref()) {
+            Some(message) => render::wrap(message, width),
+            None => vec![],
+        };
+        if let Some(expiry) = self.expiry {
+            footer.extend(render::countdown(self.picker, &self.opts, remaining_seconds(expiry, Instant::now()), width));
+        }
+        footer.extend(render::footer(self.picker, self.prompt, &self.opts, self.default, width));
+        let height = height.saturating_sub(footer.len());
+        let mut frame = if self.picker.preview.is_none() {
+            render::choice(self.picker, self.prompt, &self.opts, self.default, width, height)
+        } else {
+            self.refresh_preview();
+            let preview = self.preview.borrow();
+            let lines = preview.as_ref().map(|(_, lines)| lines.as_slice()).unwrap_or_default();
+            render::choice_with_preview(self.picker, self.prompt, &self.opts, self.default, (lines, self.preview_scroll), width, height)
+        };
+        frame.lines.extend(footer);
+        frame.bell = self.bell.take();
+        frame.flash = self.feedback.as_ref().is_some_and(|feedback| feedback.flash);
+        frame
+    }
+
+    fn handle(&mut self, key: KeyEvent) -> Step<String> {
+        self.feedback = None;
+        if self.expiry.take().is_some() {
+            log::info!("Countdown stopped by a key");
+        }
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        if self.help && !control {
+            if !self.scroll_help(key.code) {
+                self.help = false;
             }
+            return Step::Continue;
+        }
+        if key.code == KeyCode::Char('?') && !control && self.opts.iter().all(|item| item.key != '?') {
+            self.help = true;
+            self.help_scroll = 0;
+            self.help_page.take();
+            return Step::Continue;
+        }
+        if self.scroll_preview(key) {
+            return Step::Continue;
+        }
+        if let Some(new_current) = self.move_in_grid(key.code) {
+            self.opts.update_current(new_current);
+            return Step::Continue;
         }
+        let opts = &self.opts;
+        let new_current = match process_key(key.code, key.modifiers, opts) {
+            Action::Confirm => return Step::Done(Outcome::Selected(opts.current_name())),
+            Action::Cancel => return Step::Done(Outcome::Cancelled(CancelReason::Escape)),
+            Action::Interrupt => return Step::Done(Outcome::Interrupted),
+            Action::Eof => return Step::Done(Outcome::Eof),
+            Action::Suspend => return Step::Suspend,
+            Action::Invalid(c) => {
+                self.feedback_invalid(c);
+                return Step::Continue;
+            }
+            Action::Continue(new_current) => new_current,
+            Action::Next => opts.next(self.picker),
+            Action::Previous => opts.previous(self.picker),
+        };
+        if new_current != self.opts.current {
+            self.preview_scroll = 0;
+        }
+        self.opts.update_current(new_current);
+        Step::Continue
+    }
+
+    fn summary(&self, outcome: &Outcome<String>) -> Option<String> {
+        render::summary(self.picker, self.prompt, outcome)
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        let feedback = self.feedback.as_ref().map(|feedback| feedback.until);
+        let countdown = self.expiry.map(|expiry| next_second(expiry, Instant::now()));
+        feedback.into_iter().chain(countdown).min()
+    }
+
+    fn tick(&mut self) -> Step<String> {
+        let now = Instant::now();
+        if self.feedback.as_ref().is_some_and(|feedback| feedback.until <= now) {
+            self.feedback = None;
+        }
+        if let Some(expiry) = self.expiry
+            && expiry <= now
+        {
+            log::info!("Countdown expired");
+            return match self.picker.timeout.as_ref().map(|timeout| timeout.on_timeout) {
+                Some(OnTimeout::TimedOut) => Step::Done(Outcome::TimedOut),
+                _ => Step::Done(Outcome::Selected(self.opts.current_name())),
+            };
+        }
+        Step::Continue
     }
 }
 
-fn paren_strings(picker: &Picker) -> (String, String) {
-    match &picker.paren {
-        Some((left, right)) => (left.clone(), right.clone()),
-        None => ("".to_string(), "".to_string()),
+impl<'a> Choice<'a> {
+    pub(crate) fn new(picker: &'a Picker, prompt: &'a str, opts: Options) -> Self {
+        let default = opts.current;
+        Choice {
+            picker, prompt, opts, default,
+            width: Cell::new(0),
+            preview: RefCell::new(None),
+            preview_scroll: 0,
+            help: false,
+            help_scroll: 0,
+            help_page: Cell::new(render::HelpPage::default()),
+            bell: Cell::new(false),
+            feedback: None,
+            expiry: picker.timeout.as_ref().map(|timeout| Instant::now() + timeout.duration),
+        }
+    }
+
+    /// Give the feedback for the key matching no item, according to [Picker::invalid_key_feedback].
+    fn feedback_invalid(&mut self, key: char) {
+        log::info!("Invalid key: {key}");
+        let now = Instant::now();
+        match &self.picker.invalid_key_feedback {
+            InvalidKeyFeedback::Silent => {}
+            InvalidKeyFeedback::Bell => self.bell.set(true),
+            InvalidKeyFeedback::Flash => {
+                self.feedback = Some(Feedback { message: None, flash: true, until: now + FLASH_DURATION });
+            }
+            InvalidKeyFeedback::Message(format) => {
+                let message = format.replace("{key}", &key.to_string());
+                self.feedback = Some(Feedback { message: Some(message), flash: false, until: now + MESSAGE_DURATION });
+            }
+        }
+    }
+
+    /// Call the preview callback if the current item has changed since the last call.
+    fn refresh_preview(&self) {
+        let Some(preview) = &self.picker.preview else {
+            return;
+        };
+        let current = self.opts.current;
+        let mut cache = self.preview.borrow_mut();
+        if cache.as_ref().is_none_or(|(index, _)| *index != current) {
+            *cache = Some((current, preview.lines(self.opts.current_item())));
+        }
+    }
+
+    /// Scroll the preview pane with Shift+Up/Shift+Down and PageUp/PageDown.
+    /// Returns `false` if the key is not for scrolling.
+    fn scroll_preview(&mut self, key: KeyEvent) -> bool {
+        let Some(preview) = &self.picker.preview else {
+            return false;
+        };
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        let lines = self.preview.borrow().as_ref().map(|(_, lines)| lines.len()).unwrap_or(0);
+        let max = lines.saturating_sub(preview.height);
+        self.preview_scroll = match key.code {
+            KeyCode::Up if shift => self.preview_scroll.saturating_sub(1),
+            KeyCode::Down if shift => (self.preview_scroll + 1).min(max),
+            KeyCode::PageUp => self.preview_scroll.saturating_sub(preview.height),
+            KeyCode::PageDown => (self.preview_scroll + preview.height).min(max),
+            _ => return false,
+        };
+        true
     }
+
+    /// Scroll the item keys in the help overlay by Up/Down and PageUp/PageDown keys, if they overflow.
+    /// Returns `false` for the other keys, closing the help.
+    fn scroll_help(&mut self, key_code: KeyCode) -> bool {
+        let page = self.help_page.get();
+        if page.max == 0 {
+            return false;
+        }
+        let scroll = self.help_scroll.min(page.max);
+        self.help_scroll = match key_code {
+            KeyCode::Up => scroll.saturating_sub(1),
+            KeyCode::Down => (scroll + 1).min(page.max),
+            KeyCode::PageUp => scroll.saturating_sub(page.rows.max(1)),
+            KeyCode::PageDown => (scroll + page.rows.max(1)).min(page.max),
+            _ => return false,
+        };
+        true
+    }
+
+    /// Returns the index of the item in the previous/next row for Up/Down keys in [Layout::Grid].
+    /// The current item stays if there is no such row.
+    fn move_in_grid(&self, key_code: KeyCode) -> Option<usize> {
+        let width = self.width.get();
+        let layout = render::layout(self.picker, self.prompt, &self.opts, self.default, width);
+        if layout != Layout::Grid {
+            return None;
+        }
+        let columns = render::grid_columns(&self.opts, width);
+        let current = self.opts.current;
+        match key_code {
+            KeyCode::Up => Some(current.checked_sub(columns).unwrap_or(current)),
+            KeyCode::Down if current + columns < self.opts.items.len() => Some(current + columns),
+            KeyCode::Down => Some(current),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the remaining seconds of the countdown shown to the user, rounded up.
+fn remaining_seconds(expiry: Instant, now: Instant) -> u64 {
+    let remaining = expiry.saturating_duration_since(now);
+    remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+}
+
+/// Returns the time when the remaining seconds of the countdown decrease next, for redrawing the countdown.
+fn next_second(expiry: Instant, now: Instant) -> Instant {
+    let seconds = remaining_seconds(expiry, now).saturating_sub(1);
+    expiry - Duration::from_secs(seconds)
+}
+
+/// Line buffer for the text input, holds the characters and the cursor position.
+#[derive(Debug, Default)]
+struct LineBuffer {
+    chars: Vec<char>,
+    cursor: usize,
 }
 
-fn print_description(picker: &Picker, stdout: &mut std::io::Stdout, opts: &Options) {
-    use super::DescriptionShowMode;
+impl LineBuffer {
+    fn new(initial: &str) -> Self {
+        let chars = initial.chars().collect::<Vec<_>>();
+        let cursor = chars.len();
+        Self { chars, cursor }
+    }
 
-    let name_width = calculate_name_width(picker, opts);
-    match picker.description_show_mode {
-        DescriptionShowMode::All => write_all_descriptions(stdout, opts, name_width),
-        DescriptionShowMode::CurrentOnly => write_current_description(stdout, opts, name_width),
-        DescriptionShowMode::Never => {}
+    fn text(&self) -> String {
+        self.chars.iter().collect()
     }
 }
 
-fn calculate_name_width(picker: &Picker, opts: &Options) -> usize {
-    use super::DescriptionNameWidth::*;
-    match picker.description_name_width {
-        Fixed(w) => w,
-        Never => 0,
-        Auto => opts.iter().map(|item| item.long_label.len()).max().unwrap_or(0),
+enum InputAction {
+    Cancel,
+    Interrupt,
+    Eof,
+    Suspend,
+    Confirm,
+    Continue,
+}
+
+pub(crate) fn input(
+    picker: &mut Picker,
+    prompt: &str,
+    initial: &str,
+    id: Option<&str>,
+) -> Result<Outcome<String>> {
+    if let Some(outcome) = answer_input(picker, prompt, initial, id)? {
+        return Ok(outcome);
     }
+    let outcome = run_input(picker, prompt, initial)?;
+    finish(picker, prompt, outcome)
 }
 
-fn write_current_description(stdout: &mut std::io::Stdout, opts: &Options, _name_width: usize) {
-    let item = opts.current_item();
-    queue!(
-        stdout,
-        cursor::MoveToNextLine(1),
-        cursor::MoveToColumn(0),
-        terminal::Clear(terminal::ClearType::CurrentLine)
-    )
-    .ok();
-    print!(
-        "    {:6} {}",
-        item.long_label,
-        item.description.clone().unwrap_or("".to_string())
-    )
+/// Answer the input without the prompt by the preseeded answer or the [Policy], if any.
+fn answer_input(picker: &Picker, prompt: &str, initial: &str, id: Option<&str>) -> Result<Option<Outcome<String>>> {
+    if let Some(id) = id
+        && let Some(answer) = preseed::lookup(picker, id)?
+    {
+        log::info!("Answered {id} with the preseeded answer: {answer}");
+        return Ok(Some(Outcome::Selected(answer)));
+    }
+    assume(picker, prompt, || initial.to_string())
 }
 
-fn write_all_descriptions(stdout: &mut std::io::Stdout, opts: &Options, name_width: usize) {
-    for (index, item) in opts.iter().enumerate() {
-        let selected = if opts.current == index { ">" } else { " " };
-        queue!(stdout, cursor::MoveToNextLine(1), cursor::MoveToColumn(0)).ok();
-        print!(
-            "{:1} {:w$} {}",
-            selected,
-            item.long_label,
-            item.description.clone().unwrap_or("".to_string()),
-            w = name_width
-        );
+fn run_input(
+    picker: &mut Picker,
+    prompt: &str,
+    initial: &str,
+) -> Result<Outcome<String>> {
+    let mut stdout = ensure_tty(std::io::stdout())?;
+    let mut screen = screen::open(picker, &mut stdout)?;
+    let mut canvas = Canvas::new(picker.alternate_screen, picker.ephemeral);
+    log::info!("Starting input loop");
+    let mut input = Input { picker, prompt, buffer: LineBuffer::new(initial) };
+    run_loop(&mut input, &mut screen, &mut canvas, &mut stdout)
+}
+
+/// SessionScreen keeps the screen open across the prompts of [crate::Session].
+/// The screen is opened by the first prompt shown, so the prompts answered without the terminal
+/// (by the preseeded answers or the policies) do not need a TTY.
+#[derive(Default)]
+pub(crate) struct SessionScreen {
+    screen: Option<(screen::Screen, std::io::Stdout)>,
+    /// The summary lines of the answered prompts, shown above the current prompt in the alternate screen mode.
+    history: Vec<String>,
+    /// Whether the cursor is at the end of the last prompt, or of the line printed by the printer after it (keeper mode),
+    /// then the next prompt starts in the next line.
+    after_prompt: bool,
+}
+
+pub(crate) fn choose_in_session(
+    picker: &Picker,
+    session: &mut SessionScreen,
+    prompt: &str,
+    options: Options,
+    id: Option<&str>,
+) -> Result<Outcome<String>> {
+    if let Some(outcome) = answer_choice(picker, prompt, &options, id)? {
+        return Ok(outcome);
     }
+    log::info!("Starting choice loop in the session");
+    session.run(picker, prompt, &mut Choice::new(picker, prompt, options))
+}
+
+pub(crate) fn input_in_session(
+    picker: &Picker,
+    session: &mut SessionScreen,
+    prompt: &str,
+    initial: &str,
+    id: Option<&str>,
+) -> Result<Outcome<String>> {
+    if let Some(outcome) = answer_input(picker, prompt, initial, id)? {
+        return Ok(outcome);
+    }
+    log::info!("Starting input loop in the session");
+    session.run(picker, prompt, &mut Input { picker, prompt, buffer: LineBuffer::new(initial) })
+}
+
+impl SessionScreen {
+    fn run<G: Widget<Output = String>>(&mut self, picker: &Picker, prompt: &str, widget: &mut G) -> Result<Outcome<String>> {
+        if self.screen.is_none() {
+            let mut stdout = ensure_tty(std::io::stdout())?;
+            let screen = screen::open(picker, &mut stdout)?;
+            self.screen = Some((screen, stdout));
+        }
+        let (screen, stdout) = self.screen.as_mut().expect("the screen is opened above");
+        if let Some(line_open) = picker.printer.as_ref().and_then(crate::Printer::resume) {
+            self.after_prompt = line_open;
+        }
+        if self.after_prompt {
+            write!(stdout, "\r\n")?;
+            self.after_prompt = false;
+        }
+        let mut canvas = Canvas::new(picker.alternate_screen, picker.ephemeral);
+        let outcome = if picker.alternate_screen && !picker.ephemeral {
+            let mut history = History { inner: &mut *widget, lines: &self.history };
+            run_loop(&mut history, screen, &mut canvas, stdout)?
+        } else {
+            run_loop(widget, screen, &mut canvas, stdout)?
+        };
+        if picker.alternate_screen && !picker.ephemeral {
+            let line = widget.summary(&outcome)
+                .unwrap_or_else(|| render::summary_line(&crate::Summary::default(), prompt, &outcome));
+            self.history.push(line);
+        } else if !picker.ephemeral {
+            self.after_prompt = true;
+        }
+        // no prompt polls the printer until the next prompt, then the printer prints the lines and suspends by itself.
+        if let Some(printer) = &picker.printer {
+            printer.pause(picker.alternate_screen, self.after_prompt);
+        }
+        if picker.reraise_interrupt && matches!(outcome, Outcome::Interrupted) {
+            self.close(picker)?;
+            log::info!("Re-raising SIGINT");
+            reraise_interrupt();
+        }
+        Ok(outcome)
+    }
+
+    /// Restore the terminal, and print the summary lines of the answered prompts
+    /// if [Picker::summary] is set in the alternate screen mode.
+    /// The next prompt opens the screen again.
+    pub(crate) fn close(&mut self, picker: &Picker) -> Result<()> {
+        let history = std::mem::take(&mut self.history);
+        self.after_prompt = false;
+        if self.screen.take().is_none() {
+            return Ok(());
+        }
+        if picker.alternate_screen && picker.summary.is_some() {
+            let mut stdout = std::io::stdout();
+            for line in history {
+                writeln!(stdout, "{line}")?;
+            }
+            stdout.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// History shows the summary lines of the answered prompts above the widget,
+/// in the alternate screen mode of [crate::Session]. The oldest lines are omitted if they do not fit.
+struct History<'h, G> {
+    inner: &'h mut G,
+    lines: &'h [String],
+}
+
+impl<G: Widget> Widget for History<'_, G> {
+    type Output = G::Output;
+
+    fn frame(&self, width: usize, height: usize) -> Frame {
+        let mut frame = self.inner.frame(width, height);
+        let history = self.lines.iter().flat_map(|line| render::wrap(line, width)).collect::<Vec<_>>();
+        let shown = history.len().min(height.saturating_sub(frame.lines.len()));
+        let mut lines = history[history.len() - shown..].to_vec();
+        lines.append(&mut frame.lines);
+        frame.lines = lines;
+        frame.cursor = frame.cursor.map(|(row, column)| (row + shown, column));
+        frame
+    }
+
+    fn handle(&mut self, key: KeyEvent) -> Step<Self::Output> {
+        self.inner.handle(key)
+    }
+
+    fn summary(&self, outcome: &Outcome<Self::Output>) -> Option<String> {
+        self.inner.summary(outcome)
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.inner.deadline()
+    }
+
+    fn tick(&mut self) -> Step<Self::Output> {
+        self.inner.tick()
+    }
+}
+
+/// Input is the widget for entering a line of text.
+struct Input<'a> {
+    picker: &'a Picker,
+    prompt: &'a str,
+    buffer: LineBuffer,
+}
+
+impl Widget for Input<'_> {
+    type Output = String;
+
+    fn frame(&self, width: usize, _height: usize) -> Frame {
+        render::input(self.prompt, &self.buffer.chars, self.buffer.cursor, width)
+    }
+
+    fn handle(&mut self, key: KeyEvent) -> Step<String> {
+        match process_input_key(key.code, key.modifiers, &mut self.buffer) {
+            InputAction::Confirm => Step::Done(Outcome::Selected(self.buffer.text())),
+            InputAction::Cancel => Step::Done(Outcome::Cancelled(CancelReason::Escape)),
+            InputAction::Interrupt => Step::Done(Outcome::Interrupted),
+            InputAction::Eof => Step::Done(Outcome::Eof),
+            InputAction::Suspend => Step::Suspend,
+            InputAction::Continue => Step::Continue,
+        }
+    }
+
+    fn summary(&self, outcome: &Outcome<String>) -> Option<String> {
+        render::summary(self.picker, self.prompt, outcome)
+    }
+}
+
+/// Process a key event for the text input, and update the given buffer.
+fn process_input_key(key_code: KeyCode, modifiers: KeyModifiers, buffer: &mut LineBuffer) -> InputAction {
+    log::info!("Processing input key: {key_code:?} with modifiers: {modifiers:?}");
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        match key_code {
+            KeyCode::Char('c') => return InputAction::Interrupt,
+            KeyCode::Char('d') if buffer.chars.is_empty() => return InputAction::Eof,
+            KeyCode::Char('z') => return InputAction::Suspend,
+            KeyCode::Char('a') => buffer.cursor = 0,
+            KeyCode::Char('e') => buffer.cursor = buffer.chars.len(),
+            KeyCode::Char('u') => {
+                buffer.chars.drain(..buffer.cursor);
+                buffer.cursor = 0;
+            }
+            KeyCode::Char('k') => buffer.chars.truncate(buffer.cursor),
+            _ => {}
+        }
+        return InputAction::Continue;
+    }
+    match key_code {
+        KeyCode::Char(c) => {
+            buffer.chars.insert(buffer.cursor, c);
+            buffer.cursor += 1;
+        }
+        KeyCode::Backspace if buffer.cursor > 0 => {
+            buffer.cursor -= 1;
+            buffer.chars.remove(buffer.cursor);
+        }
+        KeyCode::Delete if buffer.cursor < buffer.chars.len() => {
+            buffer.chars.remove(buffer.cursor);
+        }
+        KeyCode::Left => buffer.cursor = buffer.cursor.saturating_sub(1),
+        KeyCode::Right => buffer.cursor = std::cmp::min(buffer.cursor + 1, buffer.chars.len()),
+        KeyCode::Home => buffer.cursor = 0,
+        KeyCode::End => buffer.cursor = buffer.chars.len(),
+        KeyCode::Enter => return InputAction::Confirm,
+        KeyCode::Esc => return InputAction::Cancel,
+        _ => {}
+    }
+    InputAction::Continue
 }
 
 /// Process a key event and return the resulting action.
@@ -125,14 +803,18 @@ fn process_key(key_code: KeyCode, modifiers: KeyModifiers, options: &Options) ->
     log::info!("Processing key: {key_code:?} with modifiers: {modifiers:?}");
     if let KeyCode::Char(c) = key_code {
         if c == 'c' && modifiers.contains(KeyModifiers::CONTROL) {
-            Action::Cancel
+            Action::Interrupt
+        } else if c == 'd' && modifiers.contains(KeyModifiers::CONTROL) {
+            Action::Eof
+        } else if c == 'z' && modifiers.contains(KeyModifiers::CONTROL) {
+            Action::Suspend
         } else {
             for (index, item) in options.iter().enumerate() {
                 if item.key == c {
                     return Action::Continue(index);
                 }
             }
-            Action::Continue(options.current)
+            Action::Invalid(c)
         }
     } else {
         match key_code {
@@ -147,54 +829,45 @@ fn process_key(key_code: KeyCode, modifiers: KeyModifiers, options: &Options) ->
 
 #[cfg(test)]
 mod tests {
-    mod paren_strings {
+    mod process_key {
         use super::super::*;
-
-        #[test]
-        fn test_paren_strings() {
-            let picker = crate::PickerBuilder::default().paren("()").build().unwrap();
-            let (left, right) = paren_strings(&picker);
-            assert_eq!(left, "(".to_string());
-            assert_eq!(right, ")".to_string());
-        }
-
-        #[test]
-        fn test_empty_paren() {
-            let picker = crate::PickerBuilder::default().paren("").build().unwrap();
-            let (left, right) = paren_strings(&picker);
-            assert_eq!(left, "".to_string());
-            assert_eq!(right, "".to_string());
-        }
-
-
+        use crossterm::event::{KeyCode, KeyModifiers};
         #[test]
-        fn test_only_left() {
-            let picker = crate::PickerBuilder::default().paren(":").build().unwrap();
-            let (left, right) = paren_strings(&picker);
-            assert_eq!(left, ":".to_string());
-            assert_eq!(right, "".to_string());
+        fn interrupt_with_ctrl_c() {
+            let options = crate::OptionsBuilder::default()
+                .item(crate::Item::new_full("Yes", "y", 'y', None))
+                .item(crate::Item::new_full("No", "n", 'n', None))
+                .build()
+                .unwrap();
+            let action = process_key(KeyCode::Char('c'), KeyModifiers::CONTROL, &options);
+            match action {
+                Action::Interrupt => {}
+                _ => panic!("Expected Interrupt action"),
+            }
         }
 
         #[test]
-        fn test_() {
-            let picker = crate::PickerBuilder::default().paren("(<>)").build().unwrap();
-            let (left, right) = paren_strings(&picker);
-            assert_eq!(left, "(<".to_string());
-            assert_eq!(right, ">)".to_string());
+        fn eof_with_ctrl_d() {
+            let options = crate::OptionsBuilder::default()
+                .item(crate::Item::new_full("Yes", "y", 'y', None))
+                .item(crate::Item::new_full("No", "n", 'n', None))
+                .build()
+                .unwrap();
+            let action = process_key(KeyCode::Char('d'), KeyModifiers::CONTROL, &options);
+            match action {
+                Action::Eof => {}
+                _ => panic!("Expected Eof action"),
+            }
         }
-    }
 
-    mod process_key {
-        use super::super::*;
-        use crossterm::event::{KeyCode, KeyModifiers};
         #[test]
-        fn cancel_with_ctrl_c() {
+        fn cancel_with_esc() {
             let options = crate::OptionsBuilder::default()
                 .item(crate::Item::new_full("Yes", "y", 'y', None))
                 .item(crate::Item::new_full("No", "n", 'n', None))
                 .build()
                 .unwrap();
-            let action = process_key(KeyCode::Char('c'), KeyModifiers::CONTROL, &options);
+            let action = process_key(KeyCode::Esc, KeyModifiers::NONE, &options);
             match action {
                 Action::Cancel => {}
                 _ => panic!("Expected Cancel action"),
@@ -202,16 +875,16 @@ mod tests {
         }
 
         #[test]
-        fn cancel_with_esc() {
+        fn suspend_with_ctrl_z() {
             let options = crate::OptionsBuilder::default()
                 .item(crate::Item::new_full("Yes", "y", 'y', None))
                 .item(crate::Item::new_full("No", "n", 'n', None))
                 .build()
                 .unwrap();
-            let action = process_key(KeyCode::Esc, KeyModifiers::NONE, &options);
+            let action = process_key(KeyCode::Char('z'), KeyModifiers::CONTROL, &options);
             match action {
-                Action::Cancel => {}
-                _ => panic!("Expected Cancel action"),
+                Action::Suspend => {}
+                _ => panic!("Expected Suspend action"),
             }
         }
 
@@ -244,7 +917,7 @@ mod tests {
         }
 
         #[test]
-        fn continue_unrelated_key() {
+        fn invalid_unrelated_key() {
             let options = crate::OptionsBuilder::default()
                 .item(crate::Item::new_full("Yes", "y", 'y', None))
                 .item(crate::Item::new_full("No", "n", 'n', None))
@@ -253,8 +926,8 @@ mod tests {
                 .unwrap();
             let action = process_key(KeyCode::Char('x'), KeyModifiers::NONE, &options);
             match action {
-                Action::Continue(item) => assert_eq!(item, 1),
-                _ => panic!("Expected Cancel action"),
+                Action::Invalid(c) => assert_eq!(c, 'x'),
+                _ => panic!("Expected Invalid action"),
             }
         }
 
@@ -334,52 +1007,717 @@ mod tests {
         }
     }
 
-    mod calculate_name_width {
-        use crate::{OptionsBuilder, PickerBuilder};
+    mod process_input_key {
+        use super::super::*;
+        use crossterm::event::{KeyCode, KeyModifiers};
 
         #[test]
-        fn test_fixed_width() {
-            let picker = PickerBuilder::default()
-                .description_name_width(crate::DescriptionNameWidth::Fixed(7))
-                .build()
-                .unwrap();
-            let options = OptionsBuilder::default()
-                .item(crate::Item::new_full("Short", "s", 's', None))
-                .item(crate::Item::new_full("LongerName", "l", 'l', None))
-                .build()
-                .unwrap();
-            let width = crate::routine::calculate_name_width(&picker, &options);
-            assert_eq!(width, 7);
+        fn insert_and_confirm() {
+            let mut buffer = LineBuffer::new("pick");
+            process_input_key(KeyCode::Char('-'), KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Char('a'), KeyModifiers::NONE, &mut buffer);
+            assert_eq!(buffer.text(), "pick-a");
+            match process_input_key(KeyCode::Enter, KeyModifiers::NONE, &mut buffer) {
+                InputAction::Confirm => {}
+                _ => panic!("Expected Confirm action"),
+            }
         }
 
         #[test]
-        fn test_auto_width() {
-            let picker = PickerBuilder::default()
-                .description_name_width(crate::DescriptionNameWidth::Auto)
-                .build()
-                .unwrap();
-            let options = OptionsBuilder::default()
-                .item(crate::Item::new_full("Short", "s", 's', None))
-                .item(crate::Item::new_full("LongerName", "l", 'l', None))
-                .build()
-                .unwrap();
-            let width = crate::routine::calculate_name_width(&picker, &options);
-            assert_eq!(width, "LongerName".len());
+        fn edit_in_the_middle() {
+            let mut buffer = LineBuffer::new("pick-a-boo");
+            process_input_key(KeyCode::Home, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Right, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Delete, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Char('a'), KeyModifiers::NONE, &mut buffer);
+            assert_eq!(buffer.text(), "pack-a-boo");
+            process_input_key(KeyCode::End, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Backspace, KeyModifiers::NONE, &mut buffer);
+            assert_eq!(buffer.text(), "pack-a-bo");
+            assert_eq!(buffer.cursor, 9);
         }
 
         #[test]
-        fn test_never_width() {
-            let picker = PickerBuilder::default()
-                .description_name_width(crate::DescriptionNameWidth::Never)
-                .build()
-                .unwrap();
-            let options = OptionsBuilder::default()
-                .item(crate::Item::new_full("Short", "s", 's', None))
-                .item(crate::Item::new_full("LongerName", "l", 'l', None))
+        fn kill_before_cursor() {
+            let mut buffer = LineBuffer::new("pick-a-boo");
+            process_input_key(KeyCode::Left, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Left, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Left, KeyModifiers::NONE, &mut buffer);
+            process_input_key(KeyCode::Char('u'), KeyModifiers::CONTROL, &mut buffer);
+            assert_eq!(buffer.text(), "boo");
+            assert_eq!(buffer.cursor, 0);
+        }
+
+        #[test]
+        fn cancel_with_esc() {
+            let mut buffer = LineBuffer::new("");
+            match process_input_key(KeyCode::Esc, KeyModifiers::NONE, &mut buffer) {
+                InputAction::Cancel => {}
+                _ => panic!("Expected Cancel action"),
+            }
+        }
+
+        #[test]
+        fn interrupt_with_ctrl_c() {
+            let mut buffer = LineBuffer::new("text");
+            match process_input_key(KeyCode::Char('c'), KeyModifiers::CONTROL, &mut buffer) {
+                InputAction::Interrupt => {}
+                _ => panic!("Expected Interrupt action"),
+            }
+        }
+
+        #[test]
+        fn eof_with_ctrl_d_only_on_empty_line() {
+            let mut buffer = LineBuffer::new("text");
+            match process_input_key(KeyCode::Char('d'), KeyModifiers::CONTROL, &mut buffer) {
+                InputAction::Continue => {}
+                _ => panic!("Expected Continue action"),
+            }
+            let mut buffer = LineBuffer::new("");
+            match process_input_key(KeyCode::Char('d'), KeyModifiers::CONTROL, &mut buffer) {
+                InputAction::Eof => {}
+                _ => panic!("Expected Eof action"),
+            }
+        }
+    }
+
+    mod countdown {
+        use super::super::*;
+
+        #[test]
+        fn remaining_seconds_rounded_up() {
+            let now = Instant::now();
+            assert_eq!(remaining_seconds(now + Duration::from_millis(9500), now), 10);
+            assert_eq!(remaining_seconds(now + Duration::from_secs(9), now), 9);
+            assert_eq!(remaining_seconds(now, now + Duration::from_secs(1)), 0);
+        }
+
+        #[test]
+        fn next_second_of_countdown() {
+            let now = Instant::now();
+            let expiry = now + Duration::from_millis(9500);
+            assert_eq!(next_second(expiry, now), now + Duration::from_millis(500));
+            assert_eq!(next_second(expiry, expiry), expiry);
+        }
+    }
+
+    mod run_loop {
+        use super::super::*;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        use std::collections::VecDeque;
+
+        /// Terminal feeding the scripted events.
+        /// If `output` is set, a line is printed into it on [Next::Hold], as the closure of [crate::Printer::suspend] does.
+        struct Scripted {
+            events: VecDeque<Next>,
+            size: (usize, usize),
+            output: Option<Output>,
+        }
+
+        impl Terminal for Scripted {
+            fn next_event(&mut self, _deadline: Option<Instant>) -> std::io::Result<Next> {
+                Ok(self.events.pop_front().expect("no more events"))
+            }
+
+            fn suspend(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+
+            fn size(&self) -> std::io::Result<(usize, usize)> {
+                Ok(self.size)
+            }
+
+            fn hold(&mut self) -> std::io::Result<()> {
+                match &mut self.output {
+                    Some(output) => output.write_all(b"printed while held\r\n"),
+                    None => Ok(()),
+                }
+            }
+        }
+
+        /// The output shared by the loop and [Scripted].
+        #[derive(Clone, Default)]
+        struct Output(std::rc::Rc<RefCell<Vec<u8>>>);
+
+        impl Write for Output {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        /// Terminal emulator decoding the output of [Canvas], for asserting on the screen rather than on the bytes.
+        /// It understands the sequences written by the canvas: the cursor moves, saving and restoring the cursor,
+        /// the clears, and the synchronized updates. The styles are ignored.
+        struct Emulator {
+            rows: Vec<Vec<char>>,
+            cursor: (usize, usize),
+            saved: (usize, usize),
+            synchronized: bool,
+            /// The number of the whole screen clears.
+            full_clears: usize,
+            /// The number of the characters written outside the synchronized updates.
+            unsynchronized: usize,
+        }
+
+        impl Emulator {
+            fn new(width: usize, height: usize) -> Self {
+                Emulator {
+                    rows: vec![vec![' '; width]; height],
+                    cursor: (0, 0),
+                    saved: (0, 0),
+                    synchronized: false,
+                    full_clears: 0,
+                    unsynchronized: 0,
+                }
+            }
+
+            fn decode(width: usize, height: usize, out: &str) -> Self {
+                let mut emulator = Emulator::new(width, height);
+                emulator.feed(out);
+                emulator
+            }
+
+            /// The rows on the screen without the trailing spaces, up to the last non-empty row.
+            fn lines(&self) -> Vec<String> {
+                let mut lines = self.rows.iter()
+                    .map(|row| row.iter().filter(|c| **c != '\0').collect::<String>().trim_end().to_string())
+                    .collect::<Vec<_>>();
+                while lines.last().is_some_and(String::is_empty) {
+                    lines.pop();
+                }
+                lines
+            }
+
+            fn feed(&mut self, out: &str) {
+                use unicode_width::UnicodeWidthChar;
+                let mut chars = out.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\x1b' => match chars.next() {
+                            Some('7') => self.saved = self.cursor,
+                            Some('8') => self.cursor = self.saved,
+                            Some('[') => {
+                                let mut params = String::new();
+                                while let Some(p) = chars.next_if(|c| !c.is_ascii_alphabetic()) {
+                                    params.push(p);
+                                }
+                                self.control(&params, chars.next().expect("the final byte"));
+                            }
+                            other => panic!("unknown escape: {other:?}"),
+                        },
+                        '\r' => self.cursor.1 = 0,
+                        '\n' => self.line_feed(),
+                        '\x07' => {}
+                        c => {
+                            let width = c.width().unwrap_or(0);
+                            if self.cursor.1 + width > self.rows[0].len() {
+                                self.cursor.1 = 0;
+                                self.line_feed();
+                            }
+                            let (row, column) = self.cursor;
+                            self.rows[row][column] = c;
+                            for cell in &mut self.rows[row][column + 1..column + width] {
+                                *cell = '\0';
+                            }
+                            self.cursor.1 += width;
+                            self.unsynchronized += usize::from(!self.synchronized);
+                        }
+                    }
+                }
+            }
+
+            fn control(&mut self, params: &str, command: char) {
+                let n = |default: usize| params.split(';').next().and_then(|p| p.parse().ok()).unwrap_or(default);
+                let (width, height) = (self.rows[0].len(), self.rows.len());
+                let (row, column) = self.cursor;
+                match command {
+                    'A' => self.cursor.0 = row.saturating_sub(n(1)),
+                    'B' => self.cursor.0 = (row + n(1)).min(height - 1),
+                    'E' => self.cursor = ((row + n(1)).min(height - 1), 0),
+                    'G' => self.cursor.1 = n(1) - 1,
+                    'H' => {
+                        let mut p = params.split(';').map(|p| p.parse::<usize>().unwrap_or(1));
+                        self.cursor = (p.next().unwrap_or(1) - 1, p.next().unwrap_or(1) - 1);
+                    }
+                    'J' | 'K' => {
+                        let clear_below = command == 'J';
+                        match n(0) {
+                            2 if clear_below => {
+                                self.full_clears += 1;
+                                self.rows = vec![vec![' '; width]; height];
+                            }
+                            2 => self.rows[row] = vec![' '; width],
+                            _ => {
+                                self.rows[row][column..].fill(' ');
+                                if clear_below {
+                                    self.rows[row + 1..].iter_mut().for_each(|r| r.fill(' '));
+                                }
+                            }
+                        }
+                    }
+                    'h' | 'l' if params == "?2026" => self.synchronized = command == 'h',
+                    'h' | 'l' | 'm' => {}
+                    _ => panic!("unknown control: {params}{command}"),
+                }
+            }
+
+            fn line_feed(&mut self) {
+                if self.cursor.0 + 1 < self.rows.len() {
+                    self.cursor.0 += 1;
+                } else {
+                    self.rows.remove(0);
+                    self.rows.push(vec![' '; self.rows[0].len()]);
+                }
+            }
+        }
+
+        /// The text written, without the escape sequences.
+        fn text(out: &str) -> String {
+            let mut text = String::new();
+            let mut chars = out.chars();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\x1b' => if chars.next() == Some('[') {
+                        chars.by_ref().find(char::is_ascii_alphabetic);
+                    },
+                    c => text.push(c),
+                }
+            }
+            text
+        }
+
+        fn key(code: KeyCode) -> Event {
+            Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+        }
+
+        fn run(picker: &Picker, events: Vec<Event>, alternate: bool) -> (Outcome<String>, String) {
+            run_next(picker, events.into_iter().map(Next::Event).collect(), alternate)
+        }
+
+        fn run_next(picker: &Picker, events: Vec<Next>, alternate: bool) -> (Outcome<String>, String) {
+            let options = crate::OptionsBuilder::default()
+                .item(crate::item!("Yes", "y", "I love it"))
+                .item(crate::item!("No", "n", "I don't like it"))
                 .build()
                 .unwrap();
-            let width = crate::routine::calculate_name_width(&picker, &options);
-            assert_eq!(width, 0);
+            let mut choice = Choice::new(picker, "Do you like Rust?", options);
+            let mut out = Output::default();
+            let mut terminal = Scripted { events: events.into(), size: (80, 24), output: Some(out.clone()) };
+            let outcome = run_loop(&mut choice, &mut terminal, &mut Canvas::new(alternate, picker.ephemeral), &mut out).unwrap();
+            (outcome, String::from_utf8(out.0.take()).unwrap())
+        }
+
+        #[test]
+        fn relayout_on_resize() {
+            let picker = crate::PickerBuilder::default()
+                .description_show_mode(crate::DescriptionShowMode::CurrentOnly)
+                .description_name_width(crate::DescriptionNameWidth::Fixed(6))
+                .build().unwrap();
+            let (outcome, out) = run(&picker, vec![Event::Resize(16, 24), key(KeyCode::Enter)], false);
+            assert_eq!(outcome, Outcome::Selected("Yes".to_string()));
+            let (before, after) = out.split_once("\x1b8\x1b[J").expect("the frame is cleared on resize");
+            assert!(before.contains("Do you like Rust?  Yes /n"));
+            assert!(after.contains("Do you like Rust\x1b[1E\x1b[2K?  Yes /n\x1b[1E\x1b[2K    Yes    I\x1b[1E\x1b[2K           love\x1b[1E\x1b[2K           it"));
+            // the reserved lines grow from 2 to 5
+            assert!(after.starts_with("\x1b[?2026h\x1b8\n\n\n\n\x1b[4A"));
+        }
+
+        #[test]
+        fn move_by_rows_in_grid() {
+            let picker = crate::PickerBuilder::default()
+                .layout(crate::Layout::Grid)
+                .build().unwrap();
+            let options = crate::Options::from(&["Yes", "So so", "Maybe", "No"]).unwrap();
+            let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+            choice.frame(30, 24);
+            let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+            assert!(matches!(choice.handle(down), Step::Continue));
+            assert_eq!(choice.opts.current, 2);
+            assert!(matches!(choice.handle(down), Step::Continue));
+            assert_eq!(choice.opts.current, 2);
+            assert!(matches!(choice.handle(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE)), Step::Continue));
+            assert_eq!(choice.opts.current, 3);
+            assert!(matches!(choice.handle(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)), Step::Continue));
+            assert_eq!(choice.opts.current, 1);
+        }
+
+        #[test]
+        fn move_by_rows_in_grid_beside_preview() {
+            let picker = crate::PickerBuilder::default()
+                .layout(crate::Layout::Grid)
+                .preview(crate::Preview::new(|item| item.long_label.clone()).position(crate::PreviewPosition::Beside))
+                .build().unwrap();
+            let options = crate::Options::from(&["Ant", "Bee", "Cat", "Dog", "Eel", "Fox"]).unwrap();
+            let mut choice = Choice::new(&picker, "Pick an animal", options);
+            let lines = choice.frame(40, 24).lines;
+            // the options pane is 20 columns wide, so two columns of the grid fit in it
+            assert_eq!(lines[1], "> [a] Ant    [b] Bee │");
+            assert_eq!(lines[2], "  [c] Cat    [d] Dog │");
+            let mut terminal = Scripted { events: vec![Next::Event(key(KeyCode::Down)), Next::Event(key(KeyCode::Enter))].into(), size: (40, 24), output: None };
+            let outcome = run_loop(&mut choice, &mut terminal, &mut Canvas::new(false, false), &mut Vec::new()).unwrap();
+            assert_eq!(outcome, Outcome::Selected("Cat".to_string()));
+        }
+
+        #[test]
+        fn collapse_to_summary() {
+            let picker = crate::PickerBuilder::default()
+                .description_show_mode(crate::DescriptionShowMode::CurrentOnly)
+                .summary(crate::Summary::default())
+                .build().unwrap();
+            let (outcome, out) = run(&picker, vec![key(KeyCode::Right), key(KeyCode::Enter)], false);
+            assert_eq!(outcome, Outcome::Selected("No".to_string()));
+            let screen = Emulator::decode(80, 24, &out);
+            assert_eq!(screen.lines(), ["✔ Do you like Rust? · No"]);
+            assert_eq!(screen.cursor, (0, 24));
+            let (_, out) = run(&picker, vec![key(KeyCode::Esc)], false);
+            assert_eq!(Emulator::decode(80, 24, &out).lines(), ["✖ Do you like Rust? · cancelled"]);
+        }
+
+        #[test]
+        fn erase_in_ephemeral() {
+            let picker = crate::PickerBuilder::default()
+                .description_show_mode(crate::DescriptionShowMode::CurrentOnly)
+                .summary(crate::Summary::default())
+                .ephemeral(true)
+                .build().unwrap();
+            let (outcome, out) = run(&picker, vec![key(KeyCode::Right), key(KeyCode::Enter)], false);
+            assert_eq!(outcome, Outcome::Selected("No".to_string()));
+            let screen = Emulator::decode(80, 24, &out);
+            assert!(screen.lines().is_empty());
+            assert_eq!(screen.cursor, (0, 0));
+            assert!(!out.contains('✔'));
+        }
+
+        #[test]
+        fn refresh_and_scroll_preview() {
+            let picker = crate::PickerBuilder::default()
+                .preview(crate::Preview::new(|item| format!("{}\n1\n2\n3", item.long_label)).height(2))
+                .build().unwrap();
+            let options = crate::Options::from(&["Yes", "No"]).unwrap();
+            let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+            assert!(choice.frame(80, 24).lines.ends_with(&["Yes".to_string(), "1".to_string()]));
+            let shift_down = KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT);
+            for _ in 0..3 {
+                assert!(matches!(choice.handle(shift_down), Step::Continue));
+            }
+            assert_eq!(choice.opts.current, 0);
+            assert!(choice.frame(80, 24).lines.ends_with(&["2".to_string(), "3".to_string()]));
+            assert!(matches!(choice.handle(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE)), Step::Continue));
+            assert!(choice.frame(80, 24).lines.ends_with(&["No".to_string(), "1".to_string()]));
+        }
+
+        #[test]
+        fn toggle_help() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let (outcome, out) = run(&picker, vec![key(KeyCode::Right), key(KeyCode::Char('?')), key(KeyCode::Enter), key(KeyCode::Enter)], false);
+            // Enter closes the help, and the next Enter selects the item current before the help
+            assert_eq!(outcome, Outcome::Selected("No".to_string()));
+            let (_, after) = out.split_once("Do you like Rust? (help)").expect("the help is shown");
+            // only the changed part of the prompt line is drawn, and the rest of the help is cleared
+            assert!(!text(after).contains("Do you like Rust?"));
+            assert_eq!(Emulator::decode(80, 24, &out).lines(), ["Do you like Rust? y/ No"]);
+        }
+
+        #[test]
+        fn scroll_item_keys_in_help() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let options = crate::Options::from(&["Apple", "Banana", "Cherry", "Durian", "Elderberry"]).unwrap();
+            let mut choice = Choice::new(&picker, "Fruit?", options);
+            let press = |choice: &mut Choice, code| choice.handle(KeyEvent::new(code, KeyModifiers::NONE));
+            press(&mut choice, KeyCode::Char('?'));
+            let visible = |choice: &Choice| choice.frame(80, 12).lines[9..11].to_vec();
+            assert_eq!(visible(&choice), ["  a        Apple", "  b        Banana"]);
+            press(&mut choice, KeyCode::Down);
+            assert_eq!(visible(&choice), ["  b        Banana", "  c        Cherry"]);
+            press(&mut choice, KeyCode::PageDown);
+            press(&mut choice, KeyCode::PageDown);
+            assert_eq!(visible(&choice), ["  d        Durian", "  e        Elderberry"]);
+            press(&mut choice, KeyCode::PageUp);
+            assert_eq!(visible(&choice), ["  b        Banana", "  c        Cherry"]);
+            assert!(choice.help);
+            press(&mut choice, KeyCode::Enter);
+            assert!(!choice.help);
+            assert_eq!(choice.opts.current, 0);
+        }
+
+        #[test]
+        fn question_mark_as_item_key() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let options = crate::Options::from(&["Yes", "Unsure(?)"]).unwrap();
+            let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+            assert!(matches!(choice.handle(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::NONE)), Step::Continue));
+            assert!(!choice.help);
+            assert_eq!(choice.opts.current, 1);
+        }
+
+        #[test]
+        fn feedback_invalid_key_with_message() {
+            let picker = crate::PickerBuilder::default()
+                .invalid_key_feedback(crate::InvalidKeyFeedback::message())
+                .build().unwrap();
+            let x = Next::Event(key(KeyCode::Char('x')));
+            let (_, out) = run_next(&picker, vec![x, Next::Timeout, Next::Event(key(KeyCode::Enter))], false);
+            let (before, after) = out.split_once("'x' is not an option").expect("the message is shown");
+            // the message stays until the deadline has passed, and then nothing is redrawn
+            assert!(text(after).is_empty());
+            let mut screen = Emulator::decode(80, 24, before);
+            screen.feed("'x' is not an option");
+            screen.feed(after);
+            assert_eq!(screen.lines(), ["Do you like Rust?  Yes /n", "'x' is not an option"]);
+        }
+
+        #[test]
+        fn feedback_invalid_key_with_bell_and_flash() {
+            let picker = crate::PickerBuilder::default()
+                .invalid_key_feedback(crate::InvalidKeyFeedback::Bell)
+                .build().unwrap();
+            let (_, out) = run(&picker, vec![key(KeyCode::Char('x')), key(KeyCode::Enter)], false);
+            assert_eq!(out.matches('\x07').count(), 1);
+            let picker = crate::PickerBuilder::default()
+                .invalid_key_feedback(crate::InvalidKeyFeedback::Flash)
+                .build().unwrap();
+            let (_, out) = run(&picker, vec![key(KeyCode::Char('x')), key(KeyCode::Enter)], false);
+            assert!(out.contains("\x1b[7mDo you like Rust?  Yes /n\x1b[0m"));
+        }
+
+        #[test]
+        fn select_current_on_timeout() {
+            let picker = crate::PickerBuilder::default()
+                .timeout(crate::Timeout::new(Duration::ZERO))
+                .build().unwrap();
+            let (outcome, out) = run_next(&picker, vec![Next::Timeout], false);
+            assert_eq!(outcome, Outcome::Selected("Yes".to_string()));
+            assert!(text(&out).contains("Yes in 0s, press any key to stop the countdown"));
+            let picker = crate::PickerBuilder::default()
+                .timeout(crate::Timeout::new(Duration::ZERO).on_timeout(crate::OnTimeout::TimedOut))
+                .build().unwrap();
+            let (outcome, _) = run_next(&picker, vec![Next::Timeout], false);
+            assert_eq!(outcome, Outcome::TimedOut);
+        }
+
+        #[test]
+        fn stop_countdown_by_key() {
+            let picker = crate::PickerBuilder::default()
+                .timeout(crate::Timeout::new(Duration::ZERO))
+                .build().unwrap();
+            let right = Next::Event(key(KeyCode::Right));
+            let (outcome, out) = run_next(&picker, vec![right, Next::Timeout, Next::Event(key(KeyCode::Enter))], false);
+            assert_eq!(outcome, Outcome::Selected("No".to_string()));
+            assert_eq!(out.matches("press any key").count(), 1);
+        }
+
+        #[test]
+        fn abort_by_cancel_token() {
+            let picker = crate::PickerBuilder::default()
+                .summary(crate::Summary::default())
+                .build().unwrap();
+            let (outcome, out) = run_next(&picker, vec![Next::Event(key(KeyCode::Right)), Next::Aborted], false);
+            assert_eq!(outcome, Outcome::Aborted);
+            assert!(out.contains("✖ Do you like Rust? · cancelled"));
+        }
+
+        #[test]
+        fn show_history_above_prompt() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let lines = vec!["✔ Name? · pick-a-boo".to_string(), "✔ License? · MIT".to_string()];
+            let mut input = Input { picker: &picker, prompt: "Workers?", buffer: LineBuffer::new("4") };
+            let history = History { inner: &mut input, lines: &lines };
+            let frame = history.frame(80, 24);
+            assert_eq!(frame.lines, vec!["✔ Name? · pick-a-boo", "✔ License? · MIT", "Workers? 4"]);
+            assert_eq!(frame.cursor, Some((2, 10)));
+            // the oldest lines are omitted
+            let frame = history.frame(80, 2);
+            assert_eq!(frame.lines, vec!["✔ License? · MIT", "Workers? 4"]);
+        }
+
+        #[test]
+        fn print_above_picker() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let print = Next::Print(vec!["downloaded 1/3".to_string(), "downloaded 2/3".to_string()]);
+            let (outcome, out) = run_next(&picker, vec![print, Next::Event(key(KeyCode::Enter))], false);
+            assert_eq!(outcome, Outcome::Selected("Yes".to_string()));
+            // the lines replace the frame, and the frame is drawn again below them
+            let screen = Emulator::decode(80, 24, &out);
+            assert_eq!(screen.lines(), ["downloaded 1/3", "downloaded 2/3", "Do you like Rust?  Yes /n"]);
+            assert_eq!(screen.full_clears, 0);
+        }
+
+        #[test]
+        fn draw_only_changes() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let options = crate::Options::from(&["Yes", "So so", "No"]).unwrap();
+            let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+            let mut canvas = Canvas::new(false, false);
+            let mut first = Vec::new();
+            canvas.draw(&mut first, &choice.frame(80, 24)).unwrap();
+            choice.handle(KeyEvent::new(KeyCode::Right, KeyModifiers::NONE));
+            let mut out = Vec::new();
+            canvas.draw(&mut out, &choice.frame(80, 24)).unwrap();
+            let out = String::from_utf8(out).unwrap();
+            // "Do you like Rust?  Yes /s/n" -> "Do you like Rust? y/ So so /n"
+            assert_eq!(text(&out), "y/ So so /n");
+            let mut screen = Emulator::decode(80, 24, &String::from_utf8(first).unwrap());
+            screen.feed(&out);
+            assert_eq!(screen.lines(), ["Do you like Rust? y/ So so /n"]);
+            assert_eq!((screen.full_clears, screen.unsynchronized), (0, 0));
+        }
+
+        #[test]
+        fn draw_only_changed_cells() {
+            let picker = crate::PickerBuilder::default()
+                .layout(crate::Layout::List)
+                .build().unwrap();
+            let options = crate::Options::from(&["Yes", "So so", "No"]).unwrap();
+            let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+            let mut canvas = Canvas::new(false, false);
+            let mut first = Vec::new();
+            canvas.draw(&mut first, &choice.frame(80, 24)).unwrap();
+            choice.handle(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+            let mut out = Vec::new();
+            canvas.draw(&mut out, &choice.frame(80, 24)).unwrap();
+            let out = String::from_utf8(out).unwrap();
+            // only the markers of the previous and the new current items are drawn
+            assert_eq!(text(&out), " >");
+            let mut screen = Emulator::decode(80, 24, &String::from_utf8(first).unwrap());
+            screen.feed(&out);
+            assert_eq!(screen.lines(), ["Do you like Rust?", "  [y] Yes", "> [s] So so", "  [n] No"]);
+            assert_eq!((screen.full_clears, screen.unsynchronized), (0, 0));
+        }
+
+        /// The bytes written per key press with many descriptions are a small fraction of the full redraw.
+        /// See `benches/render.rs` for the numbers.
+        #[test]
+        fn bytes_per_key_press() {
+            let picker = crate::PickerBuilder::default()
+                .layout(crate::Layout::List)
+                .description_show_mode(crate::DescriptionShowMode::All)
+                .build().unwrap();
+            let mut choice = Choice::new(&picker, "Choose an item", crate::bench_common::options(120));
+            let down = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+            let (mut diff, mut full) = (Canvas::new(true, false), Canvas::new(true, false));
+            diff.draw(&mut Vec::new(), &choice.frame(80, 200)).unwrap();
+            let (mut diff_bytes, mut full_bytes) = (0, 0);
+            for _ in 0..10 {
+                choice.handle(down);
+                let frame = choice.frame(80, 200);
+                let mut out = Vec::new();
+                diff.draw(&mut out, &frame).unwrap();
+                diff_bytes += out.len();
+                let mut out = Vec::new();
+                full.reset();
+                full.draw(&mut out, &frame).unwrap();
+                full_bytes += out.len();
+            }
+            assert!(diff_bytes * 20 < full_bytes);
+        }
+
+        #[test]
+        fn hide_while_printer_suspends() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let (outcome, out) = run_next(&picker, vec![Next::Hold, Next::Event(key(KeyCode::Enter))], false);
+            assert_eq!(outcome, Outcome::Selected("Yes".to_string()));
+            // the frame is erased before handing over, and is redrawn from scratch below the output of the closure
+            let screen = Emulator::decode(80, 24, &out);
+            assert_eq!(screen.lines(), ["printed while held", "Do you like Rust?  Yes /n"]);
+            assert_eq!(text(&out).matches("Do you like Rust?").count(), 2);
+        }
+
+        #[test]
+        fn redraw_whole_screen_on_resize_in_alternate() {
+            let picker = crate::PickerBuilder::default()
+                .alternate_screen(true)
+                .build().unwrap();
+            let (outcome, out) = run(&picker, vec![key(KeyCode::Right), Event::Resize(40, 10), key(KeyCode::Enter)], true);
+            assert_eq!(outcome, Outcome::Selected("No".to_string()));
+            // the screen is cleared once, and the whole frame is drawn again inside a synchronized update
+            let screen = Emulator::decode(80, 24, &out);
+            assert_eq!(screen.full_clears, 1);
+            assert_eq!(screen.unsynchronized, 0);
+            assert_eq!(text(&out).matches("Do you like Rust?").count(), 2);
+            assert_eq!(screen.lines(), ["Do you like Rust? y/ No"]);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    mod run_loop_async {
+        use super::super::*;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+        use std::collections::VecDeque;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        /// Terminal feeding the scripted events, and then waiting forever.
+        /// `dropped` is set on drop, as the screen restores the terminal.
+        struct Scripted {
+            events: VecDeque<Next>,
+            dropped: Arc<AtomicBool>,
+        }
+
+        impl Terminal for Scripted {
+            fn next_event(&mut self, _deadline: Option<Instant>) -> std::io::Result<Next> {
+                unreachable!("the async loop never blocks")
+            }
+
+            fn suspend(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+
+            fn size(&self) -> std::io::Result<(usize, usize)> {
+                Ok((80, 24))
+            }
+        }
+
+        impl screen::AsyncTerminal for Scripted {
+            async fn next_event_async(&mut self, _deadline: Option<Instant>) -> std::io::Result<Next> {
+                match self.events.pop_front() {
+                    Some(next) => Ok(next),
+                    None => std::future::pending().await,
+                }
+            }
+        }
+
+        impl Drop for Scripted {
+            fn drop(&mut self) {
+                self.dropped.store(true, Ordering::SeqCst);
+            }
+        }
+
+        fn scripted(keys: &[KeyCode]) -> (Scripted, Arc<AtomicBool>) {
+            let dropped = Arc::new(AtomicBool::new(false));
+            let events = keys.iter().map(|code| Next::Event(Event::Key(KeyEvent::new(*code, KeyModifiers::NONE)))).collect();
+            (Scripted { events, dropped: dropped.clone() }, dropped)
+        }
+
+        #[tokio::test]
+        async fn select_by_keys() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let options = crate::Options::from(&["Yes", "So so", "No"]).unwrap();
+            let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+            let (mut terminal, _) = scripted(&[KeyCode::Right, KeyCode::Right, KeyCode::Enter]);
+            let mut out = Vec::new();
+            let outcome = run_loop_async(&mut choice, &mut terminal, &mut Canvas::new(false, false), &mut out).await.unwrap();
+            assert_eq!(outcome, Outcome::Selected("No".to_string()));
+            assert!(String::from_utf8(out).unwrap().contains("Do you like Rust?  Yes /s/n"));
+        }
+
+        #[tokio::test]
+        async fn restore_on_drop() {
+            let picker = crate::PickerBuilder::default().build().unwrap();
+            let options = crate::Options::from(&["Yes", "No"]).unwrap();
+            let (terminal, dropped) = scripted(&[KeyCode::Right]);
+            let future = async move {
+                let mut terminal = terminal;
+                let mut choice = Choice::new(&picker, "Do you like Rust?", options);
+                run_loop_async(&mut choice, &mut terminal, &mut Canvas::new(false, false), &mut Vec::new()).await
+            };
+            // the prompt waits forever after the key, so the future is dropped at the timeout
+            let result = tokio::time::timeout(Duration::from_millis(50), future).await;
+            assert!(result.is_err());
+            assert!(dropped.load(Ordering::SeqCst));
         }
     }
 }
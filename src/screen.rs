@@ -1,18 +1,80 @@
 //! Screen management for the picker application.
 //! This module handles switching to alternate screens and managing
-//! raw mode for terminal interactions.
-use crossterm::{cursor, execute, queue, terminal};
-use crate::{DescriptionShowMode, Options, Picker};
+//! raw mode for terminal interactions, and drawing the frames on the screen.
+//!
+//! While a screen is active, the terminal state is also restored on panic (by the panic hook),
+//! and on signals (SIGTERM, SIGHUP, SIGQUIT, and SIGTSTP) on Unix platforms.
+//! The screen is also dismissed by [crate::Picker::cancel_token] between the polls,
+//! and the lines of [crate::Picker::printer] are passed to the caller for printing above the picker.
+//! Also, the screen is handed over to the closure of [crate::Printer::suspend] on request.
+use crossterm::event::{self, Event};
+use crossterm::{cursor, execute, queue, style, terminal};
+use crate::{CancelToken, Picker, Printer};
+use crate::render::Frame;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::time::Instant;
 
-pub(crate) fn new(picker: &Picker, opts: &Options, stdout: &mut std::io::Stdout) -> std::io::Result<Screen> {
+/// Interval to poll the terminal events.
+/// Between the polls, the screen checks whether the process is continued after suspension,
+/// whether the cancel token is cancelled, and whether the lines are printed (or the screen is requested) by the printer.
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+pub(crate) fn open(picker: &Picker, stdout: &mut std::io::Stdout) -> std::io::Result<Screen> {
     log::info!("Initializing screen mode: alternate_screen={}", picker.alternate_screen);
+    let hooks = Hooks { cancel: picker.cancel_token.clone(), printer: picker.printer.clone() };
     if picker.alternate_screen {
-        Ok(Screen::A(Alternate::new(stdout)?))
+        Ok(Screen::A(Alternate::new(stdout, hooks)?))
     } else {
-        Ok(Screen::K(Keeper::new(picker, opts.items.len(), stdout)?))
+        Ok(Screen::K(Keeper::new(stdout, picker.ephemeral, hooks)?))
+    }
+}
+
+/// Next is the result of waiting for the next event of the [Terminal].
+#[derive(Debug)]
+pub(crate) enum Next {
+    /// An event is read from the terminal.
+    Event(Event),
+    /// The screen is re-entered after suspension, and then the caller should redraw from scratch.
+    Resumed,
+    /// The deadline has passed without events.
+    Timeout,
+    /// The cancel token is cancelled, and then the caller should finish with [crate::Outcome::Aborted].
+    Aborted,
+    /// The lines are printed by the printer, and then the caller should print them above the frame.
+    Print(Vec<String>),
+    /// The printer requests the screen for [crate::Printer::suspend],
+    /// and then the caller should erase the frame, and call [Terminal::hold].
+    Hold,
+}
+
+/// Terminal is the source of the events for the picker loop, and controls the terminal modes.
+/// [Screen] is the implementation for the real terminal.
+pub(crate) trait Terminal {
+    /// Wait for the next event until the given deadline, or forever if `None`.
+    fn next_event(&mut self, deadline: Option<Instant>) -> std::io::Result<Next>;
+
+    /// Suspend the process, and wait until continued.
+    fn suspend(&mut self) -> std::io::Result<()>;
+
+    /// Returns the size of the terminal in (columns, rows).
+    fn size(&self) -> std::io::Result<(usize, usize)>;
+
+    /// Hand the terminal over to the closure of [crate::Printer::suspend], and wait until it returns.
+    fn hold(&mut self) -> std::io::Result<()> {
+        Ok(())
     }
 }
 
+/// AsyncTerminal is the [Terminal] waiting for the next event without blocking the thread.
+/// [AsyncScreen] is the implementation for the real terminal.
+#[cfg(feature = "async")]
+pub(crate) trait AsyncTerminal: Terminal {
+    /// Same as [Terminal::next_event], but without blocking the thread.
+    async fn next_event_async(&mut self, deadline: Option<Instant>) -> std::io::Result<Next>;
+}
+
 pub(super) enum Screen {
     /// Alternate screen mode.
     /// use crossterm's `EnterAlternateScreen` and `LeaveAlternateScreen`
@@ -22,76 +84,822 @@ pub(super) enum Screen {
     K(Keeper),
 }
 
+impl Terminal for Screen {
+    fn next_event(&mut self, deadline: Option<Instant>) -> std::io::Result<Next> {
+        loop {
+            if let Some(next) = self.interrupted() {
+                return Ok(next);
+            }
+            let interval = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(rest) => rest.min(POLL_INTERVAL),
+                    None => return Ok(Next::Timeout),
+                },
+                None => POLL_INTERVAL,
+            };
+            if event::poll(interval)? {
+                return Ok(Next::Event(event::read()?));
+            }
+            if self.resume_if_continued()? {
+                return Ok(Next::Resumed);
+            }
+        }
+    }
+
+    /// Suspend the process as Ctrl+Z does in the cooked mode, and wait until continued.
+    /// The terminal is restored by the signal handler, and is re-entered after SIGCONT.
+    /// On non-Unix platforms, this method does nothing.
+    fn suspend(&mut self) -> std::io::Result<()> {
+        #[cfg(unix)]
+        {
+            log::info!("Suspending the process");
+            signal_hook::low_level::raise(signal_hook::consts::SIGTSTP)?;
+            while !self.resume_if_continued()? {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> std::io::Result<(usize, usize)> {
+        let (columns, rows) = terminal::size()?;
+        Ok((columns as usize, rows as usize))
+    }
+
+    /// Restore the terminal to the normal mode during the closure of the printer, and re-enter the screen after it.
+    fn hold(&mut self) -> std::io::Result<()> {
+        let Some(printer) = self.guard().hooks.printer.clone() else {
+            return Ok(());
+        };
+        log::info!("Handing the screen over to the printer");
+        let alternate = matches!(self, Screen::A(_));
+        release(alternate)?;
+        printer.hand_over();
+        reacquire(alternate)
+    }
+}
+
 impl Screen {
-    pub(crate) fn prepare_write(&mut self, stdout: &mut std::io::Stdout) -> std::io::Result<()> {
+    /// Same as [Terminal::next_event], but waits for the events from the event stream without blocking the thread.
+    #[cfg(feature = "async")]
+    pub(crate) async fn next_event_async(
+        &mut self,
+        events: &mut event::EventStream,
+        deadline: Option<Instant>,
+    ) -> std::io::Result<Next> {
+        use futures_util::StreamExt;
+        loop {
+            if let Some(next) = self.interrupted() {
+                return Ok(next);
+            }
+            let interval = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(rest) => rest.min(POLL_INTERVAL),
+                    None => return Ok(Next::Timeout),
+                },
+                None => POLL_INTERVAL,
+            };
+            match tokio::time::timeout(interval, events.next()).await {
+                Ok(Some(event)) => return Ok(Next::Event(event?)),
+                Ok(None) => return Err(std::io::ErrorKind::UnexpectedEof.into()),
+                Err(_) => {}
+            }
+            if self.resume_if_continued()? {
+                return Ok(Next::Resumed);
+            }
+        }
+    }
+
+    fn guard(&self) -> &Guard {
+        match self {
+            Screen::A(g) => &g.guard,
+            Screen::K(g) => &g.guard,
+        }
+    }
+
+    /// Returns the event interrupting the wait from outside, that is, the cancellation, the request of the printer
+    /// for the screen, or the lines printed by the printer (only in the keeper mode, the alternate screen prints them on drop).
+    fn interrupted(&self) -> Option<Next> {
+        let hooks = &self.guard().hooks;
+        if hooks.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+            log::info!("Aborted by the cancel token");
+            return Some(Next::Aborted);
+        }
+        if hooks.printer.as_ref().is_some_and(Printer::suspend_requested) {
+            return Some(Next::Hold);
+        }
+        match (self, &hooks.printer) {
+            (Screen::K(_), Some(printer)) => {
+                let lines = printer.take_pending();
+                (!lines.is_empty()).then_some(Next::Print(lines))
+            }
+            _ => None,
+        }
+    }
+
+    /// Re-enter the screen if the process has been continued after suspension.
+    /// Returns `true` if re-entered, and then the caller should redraw the picker.
+    fn resume_if_continued(&mut self) -> std::io::Result<bool> {
+        if !self.guard().take_resumed() {
+            return Ok(false);
+        }
+        log::info!("Resuming the screen");
+        terminal::enable_raw_mode()?;
+        let mut stdout = std::io::stdout();
         match self {
-            Screen::A(g) => g.prepare_write(stdout),
-            Screen::K(g) => g.prepare_write(stdout),
+            Screen::A(_) => Alternate::enter(&mut stdout)?,
+            Screen::K(_) => queue!(stdout, cursor::Hide)?,
         }
+        Ok(true)
     }
 }
 
-pub(super) struct Keeper{
+/// AsyncScreen is the [Screen] reading the events from the event stream of crossterm.
+#[cfg(feature = "async")]
+pub(crate) struct AsyncScreen {
+    screen: Screen,
+    events: event::EventStream,
 }
 
-impl Keeper {
-    fn new(picker: &Picker, opts_len: usize, stdout: &mut std::io::Stdout) -> std::io::Result<Self> {
-        log::info!("Entering not-alternate screen mode");
-        let mode = picker.description_show_mode.clone();
-        let up = match mode {
-            DescriptionShowMode::All => opts_len + 1,
-            DescriptionShowMode::CurrentOnly => 1,
-            DescriptionShowMode::Never => 0,
-        };
-        for _ in 0..up { // obtain the draw space in advance
-            println!();
+#[cfg(feature = "async")]
+impl AsyncScreen {
+    pub(crate) fn new(screen: Screen) -> Self {
+        Self { screen, events: event::EventStream::new() }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Terminal for AsyncScreen {
+    fn next_event(&mut self, deadline: Option<Instant>) -> std::io::Result<Next> {
+        self.screen.next_event(deadline)
+    }
+
+    fn suspend(&mut self) -> std::io::Result<()> {
+        self.screen.suspend()
+    }
+
+    fn size(&self) -> std::io::Result<(usize, usize)> {
+        self.screen.size()
+    }
+
+    fn hold(&mut self) -> std::io::Result<()> {
+        self.screen.hold()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTerminal for AsyncScreen {
+    async fn next_event_async(&mut self, deadline: Option<Instant>) -> std::io::Result<Next> {
+        self.screen.next_event_async(&mut self.events, deadline).await
+    }
+}
+
+/// Canvas draws the frames on the screen.
+/// In the alternate screen mode, the frames are drawn from the top-left corner.
+/// In the keeper mode, the lines for the frame are reserved below the current cursor position,
+/// and the position is saved for redrawing. The reserved lines grow if the frame becomes taller.
+pub(crate) struct Canvas {
+    alternate: bool,
+    /// whether to erase the frame on finish ([crate::Picker::ephemeral]).
+    ephemeral: bool,
+    /// the number of reserved lines (keeper mode), `None` if not reserved yet.
+    reserved: Option<usize>,
+    /// the number of lines and the width of the last line of the frame drawn last.
+    last: (usize, usize),
+    /// the lines and the flash of the frame drawn last, for drawing only the changes. `None` if unknown.
+    previous: Option<(Vec<String>, bool)>,
+}
+
+impl Canvas {
+    pub(crate) fn new(alternate: bool, ephemeral: bool) -> Self {
+        Self { alternate, ephemeral, reserved: None, last: (0, 0), previous: None }
+    }
+
+    /// Draw the frame in a single synchronized update.
+    /// If the previous frame is still on the screen, only the changed cells of each line are written.
+    pub(crate) fn draw<W: Write>(&mut self, out: &mut W, frame: &Frame) -> std::io::Result<()> {
+        use unicode_width::UnicodeWidthStr;
+        let rows = frame.lines.len().max(1);
+        queue!(out, terminal::BeginSynchronizedUpdate)?;
+        if frame.bell {
+            queue!(out, style::Print('\x07'))?;
+        }
+        let fits = self.alternate || self.reserved.is_some_and(|reserved| reserved >= rows);
+        match self.previous.take() {
+            Some((previous, flash)) if fits && flash == frame.flash => self.draw_diff(out, &previous, frame)?,
+            _ => self.draw_full(out, frame, rows)?,
+        }
+        self.last = (rows, frame.lines.last().map(|line| line.width()).unwrap_or(0));
+        match frame.cursor {
+            Some((row, column)) => {
+                self.move_to(out, row, column)?;
+                queue!(out, cursor::Show)?;
+            }
+            None => queue!(out, cursor::Hide)?,
+        }
+        self.previous = Some((frame.lines.clone(), frame.flash));
+        queue!(out, terminal::EndSynchronizedUpdate)?;
+        out.flush()
+    }
+
+    /// Draw all the lines of the frame, and clear the rest of the screen below them.
+    fn draw_full<W: Write>(&mut self, out: &mut W, frame: &Frame, rows: usize) -> std::io::Result<()> {
+        if self.alternate {
+            queue!(out, cursor::MoveTo(0, 0))?;
+        } else {
+            match self.reserved {
+                Some(reserved) if reserved >= rows => queue!(out, cursor::RestorePosition)?,
+                Some(_) => {
+                    queue!(out, cursor::RestorePosition)?;
+                    self.reserve(out, rows)?;
+                }
+                None => self.reserve(out, rows)?,
+            }
+        }
+        for (index, line) in frame.lines.iter().enumerate() {
+            if index > 0 {
+                queue!(out, cursor::MoveToNextLine(1))?;
+            }
+            queue!(out, terminal::Clear(terminal::ClearType::CurrentLine))?;
+            print_line(out, line, frame.flash)?;
+        }
+        queue!(out, terminal::Clear(terminal::ClearType::FromCursorDown))
+    }
+
+    /// Draw only the changed cells of the lines from the previous frame, and clear the lines no longer used.
+    /// The changed cells are between the common prefix and the common suffix of the old and new lines,
+    /// or the rest of the line after the common prefix if the widths of the lines differ, since the rest is shifted.
+    fn draw_diff<W: Write>(&mut self, out: &mut W, previous: &[String], frame: &Frame) -> std::io::Result<()> {
+        use unicode_width::UnicodeWidthStr;
+        for (row, line) in frame.lines.iter().enumerate() {
+            let old = previous.get(row).map(String::as_str).unwrap_or_default();
+            if line == old {
+                continue;
+            }
+            let (index, column) = common_prefix(old, line);
+            let end = if old.width() == line.width() {
+                line.len() - common_suffix(&old[index..], &line[index..])
+            } else {
+                line.len()
+            };
+            self.move_to(out, row, column)?;
+            print_line(out, &line[index..end], frame.flash)?;
+            if old.width() > line.width() {
+                queue!(out, terminal::Clear(terminal::ClearType::UntilNewLine))?;
+            }
+        }
+        if previous.len() > frame.lines.len() {
+            self.move_to(out, frame.lines.len(), 0)?;
+            queue!(out, terminal::Clear(terminal::ClearType::FromCursorDown))?;
+        }
+        Ok(())
+    }
+
+    /// Forget the previous frame, then the next frame is drawn fully.
+    /// This method is called when the screen content is changed out of the canvas.
+    fn invalidate(&mut self) {
+        self.previous = None;
+    }
+
+    /// Obtain the draw space from the current cursor position, and save the position of the first line.
+    fn reserve<W: Write>(&mut self, out: &mut W, rows: usize) -> std::io::Result<()> {
+        if rows > 1 {
+            queue!(out, style::Print("\n".repeat(rows - 1)), cursor::MoveUp((rows - 1) as u16))?;
+        }
+        queue!(out, cursor::MoveToColumn(0), cursor::SavePosition)?;
+        self.reserved = Some(rows);
+        Ok(())
+    }
+
+    fn move_to<W: Write>(&mut self, out: &mut W, row: usize, column: usize) -> std::io::Result<()> {
+        if self.alternate {
+            queue!(out, cursor::MoveTo(column as u16, row as u16))
+        } else {
+            queue!(out, cursor::RestorePosition)?;
+            if row > 0 {
+                queue!(out, cursor::MoveDown(row as u16))?;
+            }
+            queue!(out, cursor::MoveToColumn(column as u16))
+        }
+    }
+
+    /// Print the lines above the frame in the keeper mode, that is, the lines replace the frame,
+    /// and the next frame is drawn below them.
+    pub(crate) fn print_above<W: Write>(&mut self, out: &mut W, lines: &[String]) -> std::io::Result<()> {
+        if self.reserved.is_some() {
+            queue!(out, cursor::RestorePosition, terminal::Clear(terminal::ClearType::FromCursorDown))?;
+        }
+        for line in lines {
+            queue!(out, style::Print(line), style::Print("\r\n"))?;
+        }
+        self.reserved = None;
+        self.invalidate();
+        out.flush()
+    }
+
+    /// Forget the reserved lines, then the next frame is drawn from the current cursor position.
+    /// This method is called after the screen is re-entered.
+    pub(crate) fn reset(&mut self) {
+        self.reserved = None;
+        self.invalidate();
+    }
+
+    /// Clear the drawn frame for the re-layout after the terminal is resized.
+    pub(crate) fn resize<W: Write>(&mut self, out: &mut W) -> std::io::Result<()> {
+        self.invalidate();
+        if self.alternate {
+            queue!(out, terminal::Clear(terminal::ClearType::All))
+        } else if self.reserved.is_some() {
+            queue!(out, cursor::RestorePosition, terminal::Clear(terminal::ClearType::FromCursorDown))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Finish drawing after the prompt is done.
+    /// In the ephemeral mode, the reserved lines are cleared, and the cursor goes back to the start position.
+    /// Otherwise, the drawn frame is replaced with the given summary lines if any, and the cursor moves to the end of the frame.
+    /// In the alternate screen mode, the summary is not drawn, since the screen is left soon.
+    pub(crate) fn finish<W: Write>(&mut self, out: &mut W, summary: Option<&[String]>) -> std::io::Result<()> {
+        if self.ephemeral {
+            return self.erase(out);
+        }
+        if let Some(lines) = summary
+            && !self.alternate
+        {
+            self.invalidate();
+            self.draw(out, &Frame { lines: lines.to_vec(), ..Frame::default() })?;
+        }
+        self.leave(out)
+    }
+
+    /// Clear all the reserved lines, and move the cursor to the start position.
+    pub(crate) fn erase<W: Write>(&mut self, out: &mut W) -> std::io::Result<()> {
+        if !self.alternate && self.reserved.is_some() {
+            queue!(out, cursor::RestorePosition, terminal::Clear(terminal::ClearType::FromCursorDown))?;
+        }
+        self.reserved = None;
+        self.invalidate();
+        out.flush()
+    }
+
+    /// Move the cursor to the end of the frame drawn last.
+    fn leave<W: Write>(&mut self, out: &mut W) -> std::io::Result<()> {
+        let (rows, width) = self.last;
+        self.move_to(out, rows.saturating_sub(1), width)?;
+        out.flush()
+    }
+}
+
+fn print_line<W: Write>(out: &mut W, line: &str, reversed: bool) -> std::io::Result<()> {
+    if reversed {
+        queue!(out, style::PrintStyledContent(style::Stylize::reverse(line)))
+    } else {
+        queue!(out, style::Print(line))
+    }
+}
+
+/// Returns the byte index and the display width of the common prefix of the given lines.
+fn common_prefix(old: &str, new: &str) -> (usize, usize) {
+    use unicode_width::UnicodeWidthChar;
+    let mut prefix = (0, 0);
+    for ((index, a), b) in new.char_indices().zip(old.chars()) {
+        if a != b {
+            break;
+        }
+        prefix = (index + a.len_utf8(), prefix.1 + a.width().unwrap_or(0));
+    }
+    prefix
+}
+
+/// Returns the byte length of the common suffix of the given lines.
+fn common_suffix(old: &str, new: &str) -> usize {
+    new.chars().rev().zip(old.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len_utf8())
+        .sum()
+}
+
+/// Restore the terminal to the normal mode temporarily for the closure of [crate::Printer::suspend],
+/// that is, show the cursor, leave the alternate screen, and disable raw mode.
+pub(crate) fn release(alternate: bool) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout();
+    if alternate {
+        execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    } else {
+        execute!(stdout, cursor::Show)?;
+    }
+    terminal::disable_raw_mode()
+}
+
+/// Re-enter the screen released by [release].
+pub(crate) fn reacquire(alternate: bool) -> std::io::Result<()> {
+    terminal::enable_raw_mode()?;
+    let mut stdout = std::io::stdout();
+    if alternate {
+        Alternate::enter(&mut stdout)?;
+    } else {
+        queue!(stdout, cursor::Hide)?;
+    }
+    stdout.flush()
+}
+
+/// Restore the terminal state, that is, show the cursor, leave the alternate screen, and disable raw mode.
+/// This function is called from the panic hook and the signal handler,
+/// so it only uses a fresh handle of stdout and ignores errors.
+fn restore_terminal(alternate: bool) {
+    let mut stdout = std::io::stdout();
+    if alternate {
+        let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+    } else {
+        let _ = execute!(stdout, cursor::Show, style::Print("\r\n"));
+    }
+    let _ = terminal::disable_raw_mode();
+}
+
+type PanicHook = Box<dyn Fn(&std::panic::PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// The screen mode currently active, used by the signal handler to restore the terminal.
+const INACTIVE: u8 = 0;
+const KEEPER: u8 = 1;
+const ALTERNATE: u8 = 2;
+static ACTIVE: AtomicU8 = AtomicU8::new(INACTIVE);
+/// Set by the signal handler when the process is continued while the screen is active.
+static RESUMED: AtomicBool = AtomicBool::new(false);
+
+/// Guard restores the terminal state on panic and on signals while the screen is active.
+/// The previous panic hook is called after restoring, and is re-installed on drop if our hook is still the one installed.
+/// Otherwise, the hook set by the caller meanwhile is kept, and our hook under it only calls the previous one.
+/// Guard also holds the hooks of the screen, since they are watched while the screen is active.
+struct Guard {
+    previous_hook: Option<Arc<PanicHook>>,
+    /// The address of our panic hook, to tell whether it is still the one installed.
+    hook_address: usize,
+    /// Cleared on drop, then our hook no longer restores the terminal.
+    live: Arc<AtomicBool>,
+    hooks: Hooks,
+}
+
+/// Hooks are the handles interrupting the screen from outside.
+struct Hooks {
+    cancel: Option<CancelToken>,
+    printer: Option<Printer>,
+}
+
+impl Guard {
+    fn install(alternate: bool, hooks: Hooks) -> Self {
+        let previous_hook = Arc::new(std::panic::take_hook());
+        let hook = Arc::clone(&previous_hook);
+        let live = Arc::new(AtomicBool::new(true));
+        let restoring = Arc::clone(&live);
+        let ours: PanicHook = Box::new(move |info| {
+            if restoring.load(Ordering::SeqCst) {
+                restore_terminal(alternate);
+            }
+            hook(info);
+        });
+        let hook_address = address_of(&ours);
+        std::panic::set_hook(ours);
+        RESUMED.store(false, Ordering::SeqCst);
+        ACTIVE.store(if alternate { ALTERNATE } else { KEEPER }, Ordering::SeqCst);
+        #[cfg(unix)]
+        signals::install();
+        if let Some(printer) = &hooks.printer {
+            printer.set_active(true);
+        }
+        Self { previous_hook: Some(previous_hook), hook_address, live, hooks }
+    }
+
+    fn take_resumed(&self) -> bool {
+        RESUMED.swap(false, Ordering::SeqCst)
+    }
+
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        ACTIVE.store(INACTIVE, Ordering::SeqCst);
+        #[cfg(unix)]
+        signals::uninstall();
+        self.live.store(false, Ordering::SeqCst);
+        // the terminal has been restored by the screen, then the lines queued are printed normally.
+        if let Some(printer) = &self.hooks.printer {
+            printer.set_active(false);
+        }
+        // the panic hook cannot be replaced while panicking, then our hook (calling the previous one) remains.
+        if std::thread::panicking() {
+            return;
+        }
+        let current = std::panic::take_hook();
+        if address_of(&current) != self.hook_address {
+            std::panic::set_hook(current);
+            return;
+        }
+        drop(current);
+        if let Some(previous) = self.previous_hook.take() {
+            match Arc::try_unwrap(previous) {
+                Ok(hook) => std::panic::set_hook(hook),
+                Err(hook) => std::panic::set_hook(Box::new(move |info| hook(info))),
+            }
+        }
+    }
+}
+
+/// Returns the address of the closure of the panic hook, identifying the hook installed.
+fn address_of(hook: &PanicHook) -> usize {
+    &**hook as *const _ as *const () as usize
+}
+
+/// Signal handling on Unix platforms.
+///
+/// The handler is installed by `sigaction` while a screen is active, and the previous dispositions are restored
+/// when the screen is left, so nothing is left in place after the picker closes.
+/// If another handler has been installed over ours meanwhile (e.g., by signal-hook or tokio, calling ours as the previous one),
+/// ours is left in place under it, but only calls the disposition it replaced while no screen is active.
+///
+/// The handler only writes the signal into a pipe and calls the previous handler, if any.
+/// The signals are handled by a thread reading the pipe, started once and living until the process exits:
+/// - SIGTERM, SIGHUP, SIGQUIT: restore the terminal, and then terminate the process.
+/// - SIGTSTP: restore the terminal, stop the process, and notify to re-enter the screen after continued.
+/// - SIGCONT: notify to re-enter the screen by [Screen::resume_if_continued].
+///
+/// The default behaviors (terminating and stopping) are emulated only if the replaced disposition was the default,
+/// that is, the handlers installed by the caller are respected.
+#[cfg(unix)]
+mod signals {
+    use super::{restore_terminal, Ordering, ACTIVE, ALTERNATE, INACTIVE, RESUMED};
+    use signal_hook::consts::{SIGCONT, SIGHUP, SIGQUIT, SIGTERM, SIGTSTP};
+    use signal_hook::low_level::emulate_default_handler;
+    use std::sync::atomic::{AtomicBool, AtomicI32, AtomicUsize};
+    use std::sync::Mutex;
+
+    const SIGNALS: [libc::c_int; 5] = [SIGTERM, SIGHUP, SIGQUIT, SIGTSTP, SIGCONT];
+
+    /// The write end of the pipe to the thread handling the signals, or -1 if the thread is not running.
+    static PIPE: AtomicI32 = AtomicI32::new(-1);
+    /// The handlers replaced by ours, called by ours. The flags tell whether they take `siginfo_t`.
+    static PREVIOUS: [AtomicUsize; SIGNALS.len()] = [const { AtomicUsize::new(libc::SIG_DFL) }; SIGNALS.len()];
+    static PREVIOUS_SIGINFO: [AtomicBool; SIGNALS.len()] = [const { AtomicBool::new(false) }; SIGNALS.len()];
+    /// The actions replaced by ours, restored by [uninstall].
+    static REPLACED: Mutex<[Option<libc::sigaction>; SIGNALS.len()]> = Mutex::new([None; SIGNALS.len()]);
+
+    pub(super) fn install() {
+        if !ensure_thread() {
+            return;
+        }
+        let mut replaced = REPLACED.lock().unwrap_or_else(|e| e.into_inner());
+        for (index, signal) in SIGNALS.into_iter().enumerate() {
+            let Some(current) = current_action(signal) else { continue };
+            if current.sa_sigaction == handler_address() {
+                continue; // left in place under another handler, still calling the one replaced before
+            }
+            PREVIOUS[index].store(current.sa_sigaction, Ordering::SeqCst);
+            PREVIOUS_SIGINFO[index].store(current.sa_flags & libc::SA_SIGINFO != 0, Ordering::SeqCst);
+            // SAFETY: the action is fully initialized, and the handler only uses async-signal-safe operations.
+            let installed = unsafe {
+                let mut action: libc::sigaction = std::mem::zeroed();
+                action.sa_sigaction = handler_address();
+                action.sa_flags = libc::SA_SIGINFO | libc::SA_RESTART;
+                libc::sigemptyset(&mut action.sa_mask);
+                libc::sigaction(signal, &action, std::ptr::null_mut()) == 0
+            };
+            if installed {
+                replaced[index] = Some(current);
+            } else {
+                log::warn!("Failed to install the handler of signal {signal}: {}", std::io::Error::last_os_error());
+            }
+        }
+    }
+
+    pub(super) fn uninstall() {
+        let mut replaced = REPLACED.lock().unwrap_or_else(|e| e.into_inner());
+        for (index, signal) in SIGNALS.into_iter().enumerate() {
+            let Some(previous) = replaced[index] else { continue };
+            if current_action(signal).is_some_and(|current| current.sa_sigaction != handler_address()) {
+                log::info!("Leaving the handler of signal {signal} under the one installed meanwhile");
+                continue;
+            }
+            // SAFETY: restoring the action queried by `install`.
+            if unsafe { libc::sigaction(signal, &previous, std::ptr::null_mut()) } == 0 {
+                replaced[index] = None;
+                PREVIOUS[index].store(libc::SIG_DFL, Ordering::SeqCst);
+            }
+        }
+    }
+
+    fn handler_address() -> usize {
+        handler as *const () as usize
+    }
+
+    fn current_action(signal: libc::c_int) -> Option<libc::sigaction> {
+        // SAFETY: querying the current action with a null new action does not change the disposition.
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            (libc::sigaction(signal, std::ptr::null(), &mut action) == 0).then_some(action)
+        }
+    }
+
+    /// Start the thread handling the signals unless running, and returns whether it is running.
+    fn ensure_thread() -> bool {
+        static STARTED: std::sync::Once = std::sync::Once::new();
+        STARTED.call_once(|| {
+            if let Err(e) = start_thread() {
+                log::warn!("Failed to start the signal handling thread: {e}");
+            }
+        });
+        PIPE.load(Ordering::SeqCst) >= 0
+    }
+
+    fn start_thread() -> std::io::Result<()> {
+        let mut fds = [0; 2];
+        // SAFETY: `fds` has room for the two descriptors, and they are owned by this module until the process exits.
+        unsafe {
+            if libc::pipe(fds.as_mut_ptr()) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            for fd in fds {
+                libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC);
+            }
+            // the handler must not block even if the thread is behind.
+            libc::fcntl(fds[1], libc::F_SETFL, libc::fcntl(fds[1], libc::F_GETFL) | libc::O_NONBLOCK);
+        }
+        let [read, write] = fds;
+        std::thread::Builder::new()
+            .name("pick-a-boo-signals".to_string())
+            .spawn(move || {
+                let mut message = [0u8; 3];
+                loop {
+                    // SAFETY: reading into the buffer of the given length.
+                    let n = unsafe { libc::read(read, message.as_mut_ptr().cast(), message.len()) };
+                    if n == message.len() as isize {
+                        handle(SIGNALS[message[0] as usize], message[1], message[2] != 0);
+                    } else if n < 0 && std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted {
+                        continue;
+                    } else {
+                        log::warn!("Stopped handling signals: {}", std::io::Error::last_os_error());
+                        return;
+                    }
+                }
+            })?;
+        PIPE.store(write, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// The signal handler, passing the signal received while a screen is active to the thread, and calling the previous handler.
+    extern "C" fn handler(signal: libc::c_int, info: *mut libc::siginfo_t, context: *mut libc::c_void) {
+        let Some(index) = SIGNALS.iter().position(|s| *s == signal) else { return };
+        let previous = PREVIOUS[index].load(Ordering::SeqCst);
+        let active = ACTIVE.load(Ordering::SeqCst);
+        if active != INACTIVE {
+            let message = [index as u8, active, (previous == libc::SIG_DFL) as u8];
+            // SAFETY: write(2) is async-signal-safe, and the pipe does not block.
+            unsafe { libc::write(PIPE.load(Ordering::SeqCst), message.as_ptr().cast(), message.len()) };
+        }
+        if previous == libc::SIG_DFL || previous == libc::SIG_IGN {
+            return;
+        }
+        // SAFETY: `previous` is the handler installed before ours, called as its flags tell.
+        unsafe {
+            if PREVIOUS_SIGINFO[index].load(Ordering::SeqCst) {
+                let previous = std::mem::transmute::<usize, extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void)>(previous);
+                previous(signal, info, context);
+            } else {
+                let previous = std::mem::transmute::<usize, extern "C" fn(libc::c_int)>(previous);
+                previous(signal);
+            }
+        }
+    }
+
+    fn handle(signal: libc::c_int, active: u8, emulate_default: bool) {
+        log::info!("Received signal: {signal} (active: {active})");
+        if signal == SIGCONT {
+            RESUMED.store(true, Ordering::SeqCst);
+            return;
+        }
+        restore_terminal(active == ALTERNATE);
+        if emulate_default {
+            let _ = emulate_default_handler(signal); // SIGTSTP stops here until SIGCONT
+        }
+        if signal == SIGTSTP && ACTIVE.load(Ordering::SeqCst) != INACTIVE {
+            RESUMED.store(true, Ordering::SeqCst);
         }
-        terminal::enable_raw_mode()?;
-        queue!(stdout, cursor::Hide, cursor::MoveUp(up as u16),cursor::SavePosition)?;
-        Ok(Self{})
     }
+}
+
+pub(super) struct Keeper {
+    guard: Guard,
+    /// If `true`, the line break is not printed on drop,
+    /// since [Canvas] has erased the picker and the cursor is back at the start position.
+    ephemeral: bool,
+}
 
-    fn prepare_write(&mut self, stdout: &mut std::io::Stdout) -> std::io::Result<()> {
-        queue!(
-            stdout,
-            cursor::RestorePosition,
-            terminal::Clear(terminal::ClearType::CurrentLine)
-        )
+impl Keeper {
+    fn new(stdout: &mut std::io::Stdout, ephemeral: bool, hooks: Hooks) -> std::io::Result<Self> {
+        log::info!("Entering not-alternate screen mode (ephemeral: {ephemeral})");
+        let guard = Guard::install(false, hooks);
+        terminal::enable_raw_mode()?;
+        queue!(stdout, cursor::Hide)?;
+        Ok(Self { guard, ephemeral })
     }
 }
 
 impl Drop for Keeper {
     fn drop(&mut self) {
         log::info!("Leaving not-alternate screen mode");
-        let _ = execute!(std::io::stdout(), cursor::Show);
-        terminal::disable_raw_mode().ok();
-        println!();
+        if self.ephemeral {
+            let _ = execute!(std::io::stdout(), cursor::Show);
+            let _ = terminal::disable_raw_mode();
+        } else {
+            restore_terminal(false);
+        }
     }
 }
 
-pub(super) struct Alternate;
+pub(super) struct Alternate {
+    guard: Guard,
+}
 
 impl Alternate {
-    fn new(stdout: &mut std::io::Stdout) -> std::io::Result<Self> {
+    fn new(stdout: &mut std::io::Stdout, hooks: Hooks) -> std::io::Result<Self> {
         log::info!("Entering alternate screen mode");
+        let guard = Guard::install(true, hooks);
         terminal::enable_raw_mode()?;
-        queue!(stdout, cursor::Hide, terminal::EnterAlternateScreen, cursor::MoveTo(0, 0), cursor::SavePosition)?;
-        Ok(Self)
+        Self::enter(stdout)?;
+        Ok(Self { guard })
     }
 
-    fn prepare_write(&mut self, stdout: &mut std::io::Stdout) -> std::io::Result<()> {
-        queue!(
-            stdout,
-            cursor::RestorePosition,
-            terminal::Clear(terminal::ClearType::CurrentLine)
-        )
+    fn enter(stdout: &mut std::io::Stdout) -> std::io::Result<()> {
+        queue!(stdout, cursor::Hide, terminal::EnterAlternateScreen, cursor::MoveTo(0, 0))
     }
 }
 
 impl Drop for Alternate {
     fn drop(&mut self) {
         log::info!("Leaving alternate screen mode");
-        let _ = execute!(std::io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
-        let _ = terminal::disable_raw_mode();
+        restore_terminal(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod guard {
+        use super::super::*;
+
+        /// The panic hook and the signal dispositions are process-wide, then the guards are installed one at a time.
+        static SERIAL: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+        fn install() -> Guard {
+            Guard::install(false, Hooks { cancel: None, printer: None })
+        }
+
+        /// Set the panic hook counting the panics, and returns the counter.
+        fn set_counting_hook() -> Arc<AtomicU8> {
+            let count = Arc::new(AtomicU8::new(0));
+            let counter = Arc::clone(&count);
+            std::panic::set_hook(Box::new(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }));
+            count
+        }
+
+        #[test]
+        fn restore_panic_hook_set_before() {
+            let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+            let count = set_counting_hook();
+            drop(install());
+            let _ = std::panic::catch_unwind(|| panic!("after the picker"));
+            let _ = std::panic::take_hook();
+            assert_eq!(count.load(Ordering::SeqCst), 1);
+        }
+
+        #[test]
+        fn keep_panic_hook_set_while_open() {
+            let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+            let guard = install();
+            let count = set_counting_hook();
+            drop(guard);
+            let _ = std::panic::catch_unwind(|| panic!("after the picker"));
+            let _ = std::panic::take_hook();
+            assert_eq!(count.load(Ordering::SeqCst), 1);
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn restore_signal_dispositions() {
+            let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+            let handler = || {
+                // SAFETY: querying the current action with a null new action does not change the disposition.
+                unsafe {
+                    let mut action: libc::sigaction = std::mem::zeroed();
+                    libc::sigaction(libc::SIGHUP, std::ptr::null(), &mut action);
+                    action.sa_sigaction
+                }
+            };
+            let before = handler();
+            let guard = install();
+            assert_ne!(handler(), before);
+            drop(guard);
+            assert_eq!(handler(), before);
+        }
     }
 }